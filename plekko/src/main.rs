@@ -37,15 +37,17 @@ use sidecar::gen::sdk::lekko::client::v1beta1::{
 };
 use sidecar::logging;
 use sidecar::logging::InsertLogFields;
+use sidecar::metrics::evaluation_error;
 use sidecar::metrics::Metrics;
 use sidecar::metrics::RuntimeMetrics;
-use sidecar::store::Store;
+use sidecar::source::BackendSource;
+use sidecar::store::{NamespaceFilter, Store};
 use sidecar::types;
 use sidecar::types::add_api_key;
 use sidecar::types::convert_repo_key;
 use sidecar::types::ConnectionCredentials;
 use sidecar::types::FeatureRequestParams;
-use sidecar::types::Mode;
+use sidecar::types::SessionKey;
 
 use std::sync::Arc;
 use std::time::Duration;
@@ -130,11 +132,11 @@ fn parse_duration(arg: &str) -> Result<std::time::Duration, humantime::DurationE
             feature: inner.key.clone(),
         };
 
-        let feature_data = store
-            .get_feature_local(feature.clone())
-            .ok_or_else(|| Status::invalid_argument("feature not found"))?;
-        if feature_data.feature.r#type() != FeatureType::Unspecified && // backwards compatibility
-            feature_data.feature.r#type() != $requested_type
+        let snapshot = store.snapshot();
+        let feature_data = snapshot
+            .get_feature_local(feature.clone())?;
+        if feature_data.info.feature.proto().r#type() != FeatureType::Unspecified && // backwards compatibility
+            feature_data.info.feature.proto().r#type() != $requested_type
         {
             return Err(tonic::Status::invalid_argument(format!(
                 "type mismatch: requested feature is not of type {:?}",
@@ -143,9 +145,12 @@ fn parse_duration(arg: &str) -> Result<std::time::Duration, humantime::DurationE
         }
         let eval_context = EvalContext {
             namespace: feature.namespace.to_owned(),
-            feature_name: feature_data.feature.key.to_owned(),
+            feature_name: feature_data.info.feature.proto().key.to_owned(),
+            resolver: Some(&snapshot),
+            ..Default::default()
         };
-        let eval_result = evaluate(&feature_data.feature, context, &eval_context)?;
+        let eval_result = evaluate(&feature_data.info.feature, context, &eval_context)
+            .map_err(evaluation_error)?;
         let result = eval_result.0;
         metrics.track_flag_evaluation(&feature, &feature_data, context, &eval_result.1);
     Ok(result)
@@ -322,7 +327,7 @@ impl ProxyConfigurationService {
                 ))
                 .await
                 .map(|resp| ConnectionCredentials {
-                    session_key: resp.into_inner().session_key,
+                    session_key: SessionKey::new(resp.into_inner().session_key),
                     repo_key: repo_key.clone(),
                     api_key: key.api_key.clone(),
                 });
@@ -336,13 +341,16 @@ impl ProxyConfigurationService {
 
         let store = Arc::new((
             Store::new(
-                self.dist_client.clone(),
                 bootstrap_data,
-                conn_creds.clone(),
-                Duration::new(15, 0),
-                Mode::Default,
-                format!("{:?}/{:?}", key.owner_name, key.repo_name),
-            ),
+                Box::new(BackendSource::new(
+                    self.dist_client.clone(),
+                    conn_creds.clone().unwrap(),
+                    Duration::new(15, 0),
+                    None,
+                )),
+                NamespaceFilter::default(),
+                1,
+            )?,
             Metrics::new(self.dist_client.clone(), key.api_key.clone(), None),
         ));
         Ok(store)
@@ -408,11 +416,10 @@ impl ConfigurationService for ProxyConfigurationService {
             feature: inner.key.clone(),
         };
 
-        let feature_data = store
-            .get_feature_local(feature.clone())
-            .ok_or_else(|| Status::invalid_argument("feature not found"))?;
-        if feature_data.feature.r#type() != FeatureType::Unspecified && // backwards compatibility
-            feature_data.feature.r#type() != requested_type
+        let snapshot = store.snapshot();
+        let feature_data = snapshot.get_feature_local(feature.clone())?;
+        if feature_data.info.feature.proto().r#type() != FeatureType::Unspecified && // backwards compatibility
+            feature_data.info.feature.proto().r#type() != requested_type
         {
             return Err(tonic::Status::invalid_argument(format!(
                 "type mismatch: requested feature is not of type {:?}",
@@ -421,9 +428,12 @@ impl ConfigurationService for ProxyConfigurationService {
         }
         let eval_context = EvalContext {
             namespace: feature.namespace.to_owned(),
-            feature_name: feature_data.feature.key.to_owned(),
+            feature_name: feature_data.info.feature.proto().key.to_owned(),
+            resolver: Some(&snapshot),
+            ..Default::default()
         };
-        let eval_result = evaluate(&feature_data.feature, context, &eval_context)?;
+        let eval_result = evaluate(&feature_data.info.feature, context, &eval_context)
+            .map_err(evaluation_error)?;
         let result = eval_result.0;
         metrics.track_flag_evaluation(&feature, &feature_data, context, &eval_result.1);
         Ok(Response::new(GetBoolValueResponse {
@@ -475,11 +485,10 @@ impl ConfigurationService for ProxyConfigurationService {
             feature: inner.key.clone(),
         };
 
-        let feature_data = store
-            .get_feature_local(feature.clone())
-            .ok_or_else(|| Status::invalid_argument("feature not found"))?;
-        if feature_data.feature.r#type() != FeatureType::Unspecified && // backwards compatibility
-            feature_data.feature.r#type() != requested_type
+        let snapshot = store.snapshot();
+        let feature_data = snapshot.get_feature_local(feature.clone())?;
+        if feature_data.info.feature.proto().r#type() != FeatureType::Unspecified && // backwards compatibility
+            feature_data.info.feature.proto().r#type() != requested_type
         {
             return Err(tonic::Status::invalid_argument(format!(
                 "type mismatch: requested feature is not of type {:?}",
@@ -488,9 +497,12 @@ impl ConfigurationService for ProxyConfigurationService {
         }
         let eval_context = EvalContext {
             namespace: feature.namespace.to_owned(),
-            feature_name: feature_data.feature.key.to_owned(),
+            feature_name: feature_data.info.feature.proto().key.to_owned(),
+            resolver: Some(&snapshot),
+            ..Default::default()
         };
-        let eval_result = evaluate(&feature_data.feature, context, &eval_context)?;
+        let eval_result = evaluate(&feature_data.info.feature, context, &eval_context)
+            .map_err(evaluation_error)?;
         let result = eval_result.0;
         metrics.track_flag_evaluation(&feature, &feature_data, context, &eval_result.1);
         let value =
@@ -542,11 +554,10 @@ impl ConfigurationService for ProxyConfigurationService {
             feature: inner.key.clone(),
         };
 
-        let feature_data = store
-            .get_feature_local(feature.clone())
-            .ok_or_else(|| Status::invalid_argument("feature not found"))?;
-        if feature_data.feature.r#type() != FeatureType::Unspecified && // backwards compatibility
-            feature_data.feature.r#type() != requested_type
+        let snapshot = store.snapshot();
+        let feature_data = snapshot.get_feature_local(feature.clone())?;
+        if feature_data.info.feature.proto().r#type() != FeatureType::Unspecified && // backwards compatibility
+            feature_data.info.feature.proto().r#type() != requested_type
         {
             return Err(tonic::Status::invalid_argument(format!(
                 "type mismatch: requested feature is not of type {:?}",
@@ -555,9 +566,12 @@ impl ConfigurationService for ProxyConfigurationService {
         }
         let eval_context = EvalContext {
             namespace: feature.namespace.to_owned(),
-            feature_name: feature_data.feature.key.to_owned(),
+            feature_name: feature_data.info.feature.proto().key.to_owned(),
+            resolver: Some(&snapshot),
+            ..Default::default()
         };
-        let eval_result = evaluate(&feature_data.feature, context, &eval_context)?;
+        let eval_result = evaluate(&feature_data.info.feature, context, &eval_context)
+            .map_err(evaluation_error)?;
         let result = eval_result.0;
         metrics.track_flag_evaluation(&feature, &feature_data, context, &eval_result.1);
 
@@ -609,11 +623,10 @@ impl ConfigurationService for ProxyConfigurationService {
             feature: inner.key.clone(),
         };
 
-        let feature_data = store
-            .get_feature_local(feature.clone())
-            .ok_or_else(|| Status::invalid_argument("feature not found"))?;
-        if feature_data.feature.r#type() != FeatureType::Unspecified && // backwards compatibility
-            feature_data.feature.r#type() != requested_type
+        let snapshot = store.snapshot();
+        let feature_data = snapshot.get_feature_local(feature.clone())?;
+        if feature_data.info.feature.proto().r#type() != FeatureType::Unspecified && // backwards compatibility
+            feature_data.info.feature.proto().r#type() != requested_type
         {
             return Err(tonic::Status::invalid_argument(format!(
                 "type mismatch: requested feature is not of type {:?}",
@@ -622,9 +635,12 @@ impl ConfigurationService for ProxyConfigurationService {
         }
         let eval_context = EvalContext {
             namespace: feature.namespace.to_owned(),
-            feature_name: feature_data.feature.key.to_owned(),
+            feature_name: feature_data.info.feature.proto().key.to_owned(),
+            resolver: Some(&snapshot),
+            ..Default::default()
         };
-        let eval_result = evaluate(&feature_data.feature, context, &eval_context)?;
+        let eval_result = evaluate(&feature_data.info.feature, context, &eval_context)
+            .map_err(evaluation_error)?;
         let result = eval_result.0;
         metrics.track_flag_evaluation(&feature, &feature_data, context, &eval_result.1);
         let value = types::from_any::<String>(&result)
@@ -675,11 +691,10 @@ impl ConfigurationService for ProxyConfigurationService {
             feature: inner.key.clone(),
         };
 
-        let feature_data = store
-            .get_feature_local(feature.clone())
-            .ok_or_else(|| Status::invalid_argument("feature not found"))?;
-        if feature_data.feature.r#type() != FeatureType::Unspecified && // backwards compatibility
-            feature_data.feature.r#type() != requested_type
+        let snapshot = store.snapshot();
+        let feature_data = snapshot.get_feature_local(feature.clone())?;
+        if feature_data.info.feature.proto().r#type() != FeatureType::Unspecified && // backwards compatibility
+            feature_data.info.feature.proto().r#type() != requested_type
         {
             return Err(tonic::Status::invalid_argument(format!(
                 "type mismatch: requested feature is not of type {:?}",
@@ -688,9 +703,12 @@ impl ConfigurationService for ProxyConfigurationService {
         }
         let eval_context = EvalContext {
             namespace: feature.namespace.to_owned(),
-            feature_name: feature_data.feature.key.to_owned(),
+            feature_name: feature_data.info.feature.proto().key.to_owned(),
+            resolver: Some(&snapshot),
+            ..Default::default()
         };
-        let eval_result = evaluate(&feature_data.feature, context, &eval_context)?;
+        let eval_result = evaluate(&feature_data.info.feature, context, &eval_context)
+            .map_err(evaluation_error)?;
         let result = eval_result.0;
         metrics.track_flag_evaluation(&feature, &feature_data, context, &eval_result.1);
         Ok(
@@ -747,11 +765,10 @@ impl ConfigurationService for ProxyConfigurationService {
             feature: inner.key.clone(),
         };
 
-        let feature_data = store
-            .get_feature_local(feature.clone())
-            .ok_or_else(|| Status::invalid_argument("feature not found"))?;
-        if feature_data.feature.r#type() != FeatureType::Unspecified && // backwards compatibility
-            feature_data.feature.r#type() != requested_type
+        let snapshot = store.snapshot();
+        let feature_data = snapshot.get_feature_local(feature.clone())?;
+        if feature_data.info.feature.proto().r#type() != FeatureType::Unspecified && // backwards compatibility
+            feature_data.info.feature.proto().r#type() != requested_type
         {
             return Err(tonic::Status::invalid_argument(format!(
                 "type mismatch: requested feature is not of type {:?}",
@@ -760,9 +777,12 @@ impl ConfigurationService for ProxyConfigurationService {
         }
         let eval_context = EvalContext {
             namespace: feature.namespace.to_owned(),
-            feature_name: feature_data.feature.key.to_owned(),
+            feature_name: feature_data.info.feature.proto().key.to_owned(),
+            resolver: Some(&snapshot),
+            ..Default::default()
         };
-        let eval_result = evaluate(&feature_data.feature, context, &eval_context)?;
+        let eval_result = evaluate(&feature_data.info.feature, context, &eval_context)
+            .map_err(evaluation_error)?;
         let result = eval_result.0;
         metrics.track_flag_evaluation(&feature, &feature_data, context, &eval_result.1);
         let value = types::from_any::<prost_types::Value>(&result)
@@ -855,7 +875,7 @@ impl ProxyDistributionService {
                 ))
                 .await
                 .map(|resp| ConnectionCredentials {
-                    session_key: resp.into_inner().session_key,
+                    session_key: SessionKey::new(resp.into_inner().session_key),
                     repo_key: repo_key.clone(),
                     api_key: key.api_key.clone(),
                 });
@@ -869,13 +889,16 @@ impl ProxyDistributionService {
 
         let store = Arc::new((
             Store::new(
-                self.dist_client.clone(),
                 bootstrap_data,
-                conn_creds.clone(),
-                Duration::new(15, 0),
-                Mode::Default,
-                format!("{:?}/{:?}", key.owner_name, key.repo_name),
-            ),
+                Box::new(BackendSource::new(
+                    self.dist_client.clone(),
+                    conn_creds.clone().unwrap(),
+                    Duration::new(15, 0),
+                    None,
+                )),
+                NamespaceFilter::default(),
+                1,
+            )?,
             Metrics::new(self.dist_client.clone(), key.api_key.clone(), None),
         ));
         Ok(store)
@@ -948,7 +971,7 @@ impl DistributionService for ProxyDistributionService {
             .unwrap(); // TODO - not sure how error prop works in rust
 
         let (version, namespaces, file_descriptor_set) =
-            store.get_repo_contents_local(&inner.namespace_name, &inner.feature_name);
+            store.get_repo_contents_local(&inner.namespace_name, &inner.feature_name)?;
         Ok(Response::new(GetRepositoryContentsResponse {
             namespaces,
             commit_sha: version,
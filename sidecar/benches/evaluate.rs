@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use prost_types::{value::Kind, Any, ListValue};
+use sidecar::{
+    evaluate::{
+        evaluator::{evaluate, EvalContext},
+        plan::{CompiledFeature, Interner},
+    },
+    gen::{
+        cli::lekko::{
+            feature::v1beta1::{Constraint, Feature, Tree},
+            rules::v1beta3::{
+                call_expression::{Bucket, Function},
+                rule::Rule as RuleKind,
+                Atom, CallExpression, ComparisonOperator, LogicalExpression, LogicalOperator, Rule,
+            },
+        },
+        sdk::lekko::client::v1beta1::{value::Kind as LekkoKind, Value},
+    },
+    types::to_any,
+};
+
+// Trees that are typical of features in a config repository. Each is evaluated
+// with a context that matches one of its later constraints.
+
+fn int_any(i: i64) -> Any {
+    Any {
+        type_url: String::from("type.googleapis.com/google.protobuf.Int64Value"),
+        value: to_any(&i).value,
+    }
+}
+
+fn atom(key: &str, op: ComparisonOperator, value: Kind) -> Rule {
+    Rule {
+        rule: Some(RuleKind::Atom(Atom {
+            context_key: key.to_owned(),
+            comparison_value: Some(prost_types::Value { kind: Some(value) }),
+            comparison_operator: op.into(),
+        })),
+        ..Default::default()
+    }
+}
+
+fn and(rules: Vec<Rule>) -> Rule {
+    Rule {
+        rule: Some(RuleKind::LogicalExpression(LogicalExpression {
+            rules,
+            logical_operator: LogicalOperator::And.into(),
+        })),
+        ..Default::default()
+    }
+}
+
+fn bucket(key: &str, threshold: u32) -> Rule {
+    Rule {
+        rule: Some(RuleKind::CallExpression(CallExpression {
+            function: Some(Function::Bucket(Bucket {
+                context_key: key.to_owned(),
+                threshold,
+            })),
+        })),
+        ..Default::default()
+    }
+}
+
+fn constraint(rule: Rule, value: Option<i64>, constraints: Vec<Constraint>) -> Constraint {
+    Constraint {
+        value: value.map(int_any),
+        rule_ast_new: Some(rule),
+        constraints,
+        ..Default::default()
+    }
+}
+
+fn feature(key: &str, constraints: Vec<Constraint>) -> Feature {
+    Feature {
+        key: key.to_owned(),
+        tree: Some(Tree {
+            default: Some(int_any(0)),
+            constraints,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn string(s: &str) -> Kind {
+    Kind::StringValue(s.to_owned())
+}
+
+// Ten equality checks on the same key, matching the last one.
+fn flat() -> (Feature, HashMap<String, Value>) {
+    let constraints = (0..10)
+        .map(|i| {
+            constraint(
+                atom(
+                    "country",
+                    ComparisonOperator::Equals,
+                    string(&format!("c{i}")),
+                ),
+                Some(i),
+                vec![],
+            )
+        })
+        .collect();
+    let context = HashMap::from([(
+        String::from("country"),
+        Value {
+            kind: Some(LekkoKind::StringValue(String::from("c9"))),
+        },
+    )]);
+    (feature("flat", constraints), context)
+}
+
+// Membership in an allowlist of 1000 user ids.
+fn allowlist() -> (Feature, HashMap<String, Value>) {
+    let ids = Kind::ListValue(ListValue {
+        values: (0..1000)
+            .map(|i| prost_types::Value {
+                kind: Some(string(&format!("user-{i}"))),
+            })
+            .collect(),
+    });
+    let constraints = vec![constraint(
+        atom("user_id", ComparisonOperator::ContainedWithin, ids),
+        Some(1),
+        vec![],
+    )];
+    let context = HashMap::from([(
+        String::from("user_id"),
+        Value {
+            kind: Some(LekkoKind::StringValue(String::from("user-999"))),
+        },
+    )]);
+    (feature("allowlist", constraints), context)
+}
+
+// A percentage rollout scoped to a region, nested under an environment check.
+fn rollout() -> (Feature, HashMap<String, Value>) {
+    let constraints = vec![
+        constraint(
+            atom("env", ComparisonOperator::Equals, string("staging")),
+            Some(1),
+            vec![],
+        ),
+        constraint(
+            atom("env", ComparisonOperator::Equals, string("prod")),
+            None,
+            vec![
+                constraint(
+                    and(vec![
+                        atom("region", ComparisonOperator::StartsWith, string("us-")),
+                        atom(
+                            "version",
+                            ComparisonOperator::GreaterThan,
+                            Kind::NumberValue(3.0),
+                        ),
+                    ]),
+                    None,
+                    vec![constraint(bucket("user_id", 100000), Some(2), vec![])],
+                ),
+                constraint(bucket("user_id", 50000), Some(3), vec![]),
+            ],
+        ),
+    ];
+    let context = HashMap::from([
+        (
+            String::from("env"),
+            Value {
+                kind: Some(LekkoKind::StringValue(String::from("prod"))),
+            },
+        ),
+        (
+            String::from("region"),
+            Value {
+                kind: Some(LekkoKind::StringValue(String::from("us-east-1"))),
+            },
+        ),
+        (
+            String::from("version"),
+            Value {
+                kind: Some(LekkoKind::IntValue(4)),
+            },
+        ),
+        (
+            String::from("user_id"),
+            Value {
+                kind: Some(LekkoKind::IntValue(12345)),
+            },
+        ),
+    ]);
+    (feature("rollout", constraints), context)
+}
+
+fn bench_evaluate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("feature");
+    for (name, (feature, context)) in [
+        ("flat", flat()),
+        ("allowlist", allowlist()),
+        ("rollout", rollout()),
+    ] {
+        let eval_context = EvalContext {
+            namespace: String::from("ns"),
+            feature_name: feature.key.clone(),
+            ..Default::default()
+        };
+        let compiled = CompiledFeature::new("ns", feature.clone(), &mut Interner::default());
+        group.bench_with_input(
+            BenchmarkId::new("evaluate", name),
+            &context,
+            |b, context| b.iter(|| evaluate(black_box(&compiled), context, &eval_context).unwrap()),
+        );
+        // The cost of building the plan, which is paid once when the feature is loaded.
+        group.bench_function(BenchmarkId::new("compile", name), |b| {
+            b.iter(|| {
+                CompiledFeature::new("ns", black_box(&feature).clone(), &mut Interner::default())
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_evaluate);
+criterion_main!(benches);
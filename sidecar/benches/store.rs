@@ -0,0 +1,193 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use prost_types::{value::Kind, Any};
+use sidecar::{
+    evaluate::evaluator::{evaluate, EvalContext},
+    gen::{
+        cli::lekko::{
+            backend::v1beta1::{Feature, GetRepositoryContentsResponse, Namespace, RepositoryKey},
+            feature::v1beta1::{Constraint, Feature as FeatureProto, Tree},
+            rules::v1beta3::{rule::Rule as RuleKind, Atom, ComparisonOperator, Rule},
+        },
+        sdk::lekko::client::v1beta1::{value::Kind as LekkoKind, Value},
+    },
+    source::ConfigSource,
+    store::{NamespaceFilter, Store, Updates},
+    types::{to_any, FeatureRequestParams},
+};
+
+const NAMESPACES: usize = 10;
+const FEATURES_PER_NAMESPACE: usize = 100;
+
+fn int_any(i: i64) -> Any {
+    Any {
+        type_url: String::from("type.googleapis.com/google.protobuf.Int64Value"),
+        value: to_any(&i).value,
+    }
+}
+
+// A feature with ten equality checks on the same key.
+fn feature(name: &str) -> FeatureProto {
+    let constraints = (0..10)
+        .map(|i| Constraint {
+            value: Some(int_any(i)),
+            rule_ast_new: Some(Rule {
+                rule: Some(RuleKind::Atom(Atom {
+                    context_key: String::from("country"),
+                    comparison_value: Some(prost_types::Value {
+                        kind: Some(Kind::StringValue(format!("c{i}"))),
+                    }),
+                    comparison_operator: ComparisonOperator::Equals.into(),
+                })),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .collect();
+    FeatureProto {
+        key: name.to_owned(),
+        tree: Some(Tree {
+            default: Some(int_any(0)),
+            constraints,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+// A repository that is large enough for loading a new version to take a while.
+fn contents(commit_sha: &str) -> GetRepositoryContentsResponse {
+    GetRepositoryContentsResponse {
+        commit_sha: commit_sha.to_owned(),
+        namespaces: (0..NAMESPACES)
+            .map(|ns| Namespace {
+                name: format!("ns{ns}"),
+                features: (0..FEATURES_PER_NAMESPACE)
+                    .map(|f| Feature {
+                        name: format!("feature{f}"),
+                        sha: format!("sha{f}"),
+                        feature: Some(feature(&format!("feature{f}"))),
+                    })
+                    .collect(),
+            })
+            .collect(),
+        file_descriptor_set: None,
+    }
+}
+
+// A source without new versions, so that the store is only updated by the
+// benchmark.
+struct FixedSource;
+
+#[tonic::async_trait]
+impl ConfigSource for FixedSource {
+    async fn load(
+        &self,
+        _namespaces: &NamespaceFilter,
+    ) -> Result<GetRepositoryContentsResponse, tonic::Status> {
+        Ok(contents("initial"))
+    }
+
+    async fn watch(&mut self, _updates: &Updates) {}
+}
+
+fn store(runtime: &tokio::runtime::Runtime) -> Store {
+    runtime
+        .block_on(Store::from_source(
+            Box::new(FixedSource),
+            NamespaceFilter::default(),
+            1,
+        ))
+        .unwrap()
+}
+
+// Looks up and evaluates a feature, the same way ConfigurationService does.
+fn read(store: &Store, context: &HashMap<String, Value>, i: usize) {
+    let snapshot = store.snapshot();
+    let namespace = format!("ns{}", i % NAMESPACES);
+    let name = format!("feature{}", i % FEATURES_PER_NAMESPACE);
+    let feature_data = snapshot
+        .get_feature_local(FeatureRequestParams {
+            rk: RepositoryKey::default(),
+            namespace: namespace.clone(),
+            feature: name.clone(),
+        })
+        .unwrap();
+    let eval_context = EvalContext {
+        namespace,
+        feature_name: name,
+        resolver: Some(&snapshot),
+        ..Default::default()
+    };
+    evaluate(&feature_data.info.feature, context, &eval_context).unwrap();
+}
+
+// Measures the time for each of `readers` threads to do `iters` reads, optionally
+// while another thread keeps loading new versions of the repository.
+fn run(store: &Arc<Store>, readers: usize, iters: u64, with_writer: bool) -> Duration {
+    let context = Arc::new(HashMap::from([(
+        String::from("country"),
+        Value {
+            kind: Some(LekkoKind::StringValue(String::from("c9"))),
+        },
+    )]));
+    let done = Arc::new(AtomicBool::new(false));
+    let writer = with_writer.then(|| {
+        let (store, done) = (store.clone(), done.clone());
+        thread::spawn(move || {
+            let mut version = 0;
+            while !done.load(Ordering::Relaxed) {
+                version += 1;
+                store.load(contents(&format!("v{version}"))).unwrap();
+            }
+        })
+    });
+    let start = Instant::now();
+    let handles: Vec<_> = (0..readers)
+        .map(|r| {
+            let (store, context) = (store.clone(), context.clone());
+            thread::spawn(move || {
+                for i in 0..iters as usize {
+                    read(&store, &context, i + r);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let elapsed = start.elapsed();
+    done.store(true, Ordering::Relaxed);
+    if let Some(writer) = writer {
+        writer.join().unwrap();
+    }
+    elapsed
+}
+
+fn bench_concurrent_reads(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let store = Arc::new(store(&runtime));
+    let mut group = c.benchmark_group("store");
+    for readers in [1, 4, 8] {
+        // Each iteration is one read on every reader thread.
+        group.throughput(Throughput::Elements(readers as u64));
+        for (name, with_writer) in [("read", false), ("read_while_loading", true)] {
+            group.bench_function(BenchmarkId::new(name, readers), |b| {
+                b.iter_custom(|iters| run(&store, readers, iters, with_writer))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_concurrent_reads);
+criterion_main!(benches);
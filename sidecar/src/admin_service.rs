@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use crate::{
+    evaluate::{
+        evaluator::{evaluate, EvalContext},
+        trace::Tracer,
+    },
+    gen::sdk::lekko::client::v1beta1::RepositoryKey,
+    gen::sidecar::lekko::sidecar::v1beta1::{
+        admin_service_server::AdminService, ExplainValueRequest, ExplainValueResponse,
+        ListSnapshotsRequest, ListSnapshotsResponse, PinSnapshotRequest, PinSnapshotResponse,
+        UnpinSnapshotRequest, UnpinSnapshotResponse,
+    },
+    repos::{Repo, Repos},
+    types::{convert_repo_key, FeatureRequestParams, TypeMismatchPolicy},
+};
+
+// Service for operators of the sidecar, used to debug how configuration is
+// being served. It is not meant to be called by the language-native SDKs.
+pub struct Service {
+    pub repos: Arc<Repos>,
+    pub type_mismatch_policy: TypeMismatchPolicy,
+}
+
+impl Service {
+    // The repository a request is for. Requests to manage snapshots may leave out
+    // the repo key if the sidecar serves a single repository.
+    fn repo(&self, repo_key: Option<&RepositoryKey>) -> Result<&Arc<Repo>, Status> {
+        match repo_key {
+            Some(rk) => self.repos.get(&rk.owner_name, &rk.repo_name),
+            None => self.repos.only(),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl AdminService for Service {
+    async fn explain_value(
+        &self,
+        request: Request<ExplainValueRequest>,
+    ) -> Result<tonic::Response<ExplainValueResponse>, tonic::Status> {
+        let inner = request.into_inner();
+        let requested_rk = inner
+            .repo_key
+            .as_ref()
+            .ok_or_else(|| Status::invalid_argument("no repo key provided"))?;
+        let store = &self.repo(Some(requested_rk))?.store;
+        let snapshot = if inner.commit_sha.is_empty() {
+            store.snapshot()
+        } else {
+            store.snapshot_at(&inner.commit_sha).ok_or_else(|| {
+                Status::not_found(format!("commit sha {} is not retained", inner.commit_sha))
+            })?
+        };
+        let feature_data = snapshot.get_feature_local(FeatureRequestParams {
+            rk: convert_repo_key(requested_rk),
+            namespace: inner.namespace.clone(),
+            feature: inner.key.clone(),
+        })?;
+        let tracer = Tracer::default();
+        let eval_context = EvalContext {
+            namespace: inner.namespace.clone(),
+            feature_name: feature_data.info.feature.proto().key.to_owned(),
+            resolver: Some(&snapshot),
+            tracer: Some(&tracer),
+            type_mismatch_policy: self.type_mismatch_policy,
+            ..Default::default()
+        };
+        // Evaluation errors are part of the explanation, rather than a failure of the rpc.
+        let result = evaluate(&feature_data.info.feature, &inner.context, &eval_context);
+        let mut response = ExplainValueResponse {
+            commit_sha: feature_data.commit_sha.to_string(),
+            feature_sha: feature_data.info.version.clone(),
+            ..Default::default()
+        };
+        match result {
+            Ok((value, path)) => {
+                response.value = Some(value);
+                response.result_path = path.iter().map(|i| *i as i32).collect();
+            }
+            Err(e) => response.error = e.to_string(),
+        }
+        response.constraints = tracer.into_constraints();
+        Ok(Response::new(response))
+    }
+
+    async fn list_snapshots(
+        &self,
+        request: Request<ListSnapshotsRequest>,
+    ) -> Result<tonic::Response<ListSnapshotsResponse>, tonic::Status> {
+        let store = &self.repo(request.get_ref().repo_key.as_ref())?.store;
+        Ok(Response::new(ListSnapshotsResponse {
+            commit_shas: store.retained_versions(),
+            current_commit_sha: store.get_version_local(),
+            pinned_commit_sha: store.pinned_version().unwrap_or_default(),
+        }))
+    }
+
+    async fn pin_snapshot(
+        &self,
+        request: Request<PinSnapshotRequest>,
+    ) -> Result<tonic::Response<PinSnapshotResponse>, tonic::Status> {
+        let store = &self.repo(request.get_ref().repo_key.as_ref())?.store;
+        store.pin(&request.get_ref().commit_sha)?;
+        Ok(Response::new(PinSnapshotResponse {}))
+    }
+
+    async fn unpin_snapshot(
+        &self,
+        request: Request<UnpinSnapshotRequest>,
+    ) -> Result<tonic::Response<UnpinSnapshotResponse>, tonic::Status> {
+        let store = &self.repo(request.get_ref().repo_key.as_ref())?.store;
+        Ok(Response::new(UnpinSnapshotResponse {
+            current_commit_sha: store.unpin(),
+        }))
+    }
+}
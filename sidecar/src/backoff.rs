@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+// Backoff computes how long to wait before retrying after consecutive failures.
+// It uses capped exponential backoff with full jitter: after n failures, the wait
+// is uniformly random in [0, min(max, base * 2^n)]. The randomness spreads out
+// retries from many sidecars that started failing at the same time.
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    failures: u32,
+}
+
+impl Backoff {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max: max.max(base),
+            failures: 0,
+        }
+    }
+
+    // Records a failure, and returns how long to wait before retrying.
+    pub fn fail(&mut self) -> Duration {
+        self.failures = self.failures.saturating_add(1);
+        jitter(self.ceiling())
+    }
+
+    pub fn reset(&mut self) {
+        self.failures = 0;
+    }
+
+    // The number of failures since the last reset.
+    pub fn failures(&self) -> u32 {
+        self.failures
+    }
+
+    // The longest wait after the current number of failures.
+    fn ceiling(&self) -> Duration {
+        // Past 2^31 the multiplier doesn't matter, the cap applies.
+        let multiplier = 1_u32 << self.failures.min(31);
+        self.base
+            .checked_mul(multiplier)
+            .map_or(self.max, |d| d.min(self.max))
+    }
+}
+
+// A uniformly random duration in [0, max].
+pub fn jitter(max: Duration) -> Duration {
+    rand::thread_rng().gen_range(Duration::ZERO..=max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let base = Duration::from_secs(1);
+        let max = Duration::from_secs(60);
+        let mut backoff = Backoff::new(base, max);
+        for (failures, ceiling) in [(1, 2), (2, 4), (3, 8), (4, 16), (5, 32), (6, 60), (7, 60)] {
+            let wait = backoff.fail();
+            assert_eq!(backoff.failures(), failures);
+            assert_eq!(backoff.ceiling(), Duration::from_secs(ceiling));
+            assert!(wait <= Duration::from_secs(ceiling), "{wait:?}");
+        }
+        // a long outage doesn't overflow
+        for _ in 0..100 {
+            assert!(backoff.fail() <= max);
+        }
+        backoff.reset();
+        assert_eq!(backoff.failures(), 0);
+        assert_eq!(backoff.ceiling(), base);
+    }
+
+    #[test]
+    fn test_max_below_base() {
+        let mut backoff = Backoff::new(Duration::from_secs(10), Duration::from_secs(1));
+        backoff.fail();
+        assert_eq!(backoff.ceiling(), Duration::from_secs(10));
+    }
+}
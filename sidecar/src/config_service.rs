@@ -5,7 +5,7 @@ use tonic::{Request, Response, Status};
 
 use crate::{
     evaluate::evaluator::{evaluate, EvalContext},
-    gen::cli::lekko::{backend::v1beta1::RepositoryKey, feature::v1beta1::FeatureType},
+    gen::cli::lekko::feature::v1beta1::FeatureType,
     gen::sdk::lekko::client::v1beta1::{
         configuration_service_server::ConfigurationService, Any as LekkoAny, DeregisterRequest,
         DeregisterResponse, GetBoolValueRequest, GetBoolValueResponse, GetFloatValueRequest,
@@ -14,18 +14,17 @@ use crate::{
         GetStringValueResponse, RegisterRequest, RegisterResponse, Value,
     },
     logging::InsertLogFields,
-    metrics::Metrics,
-    store::Store,
-    types::{self, convert_repo_key, FeatureRequestParams, Mode},
+    metrics::evaluation_error,
+    repos::Repos,
+    types::{self, convert_repo_key, FeatureRequestParams, Mode, TypeMismatchPolicy},
 };
 
 // This is the main rpc entrypoint into the sidecar. All host pods will communicate with the
 // sidecar via this Service, using the language-native SDK.
 pub struct Service {
-    pub store: Arc<Store>,
+    pub repos: Arc<Repos>,
     pub mode: Mode,
-    pub metrics: Option<Metrics>,
-    pub repo_key: RepositoryKey,
+    pub type_mismatch_policy: TypeMismatchPolicy,
 }
 
 impl Service {
@@ -35,12 +34,15 @@ impl Service {
         context: &HashMap<String, Value>,
         requested_type: FeatureType,
     ) -> Result<Any, tonic::Status> {
-        let feature_data = self
-            .store
-            .get_feature_local(feature.clone())
-            .ok_or_else(|| Status::invalid_argument("feature not found"))?;
-        if feature_data.feature.r#type() != FeatureType::Unspecified && // backwards compatibility
-            feature_data.feature.r#type() != requested_type
+        // Evaluate against a single snapshot, so that features referenced by this one
+        // are read from the same version of the repository.
+        let repo = self
+            .repos
+            .get(&feature.rk.owner_name, &feature.rk.repo_name)?;
+        let snapshot = repo.store.snapshot();
+        let feature_data = snapshot.get_feature_local(feature.clone())?;
+        if feature_data.info.feature.proto().r#type() != FeatureType::Unspecified && // backwards compatibility
+            feature_data.info.feature.proto().r#type() != requested_type
         {
             return Err(tonic::Status::invalid_argument(format!(
                 "type mismatch: requested feature is not of type {:?}",
@@ -49,10 +51,14 @@ impl Service {
         }
         let eval_context = EvalContext {
             namespace: feature.namespace.to_owned(),
-            feature_name: feature_data.feature.key.to_owned(),
+            feature_name: feature_data.info.feature.proto().key.to_owned(),
+            resolver: Some(&snapshot),
+            type_mismatch_policy: self.type_mismatch_policy,
+            ..Default::default()
         };
-        let eval_result = evaluate(&feature_data.feature, context, &eval_context)?;
-        if let Some(m) = self.metrics.as_ref() {
+        let eval_result = evaluate(&feature_data.info.feature, context, &eval_context)
+            .map_err(evaluation_error)?;
+        if let Some(m) = repo.metrics.as_ref() {
             m.track_flag_evaluation(&feature, &feature_data, context, &eval_result.1);
         }
         Ok(eval_result.0)
@@ -69,14 +75,8 @@ impl ConfigurationService for Service {
             .into_inner()
             .repo_key
             .ok_or_else(|| Status::invalid_argument("no repo key provided"))?;
-        if self.repo_key.owner_name != requested_rk.owner_name
-            || self.repo_key.repo_name != requested_rk.repo_name
-        {
-            return Err(Status::invalid_argument(format!(
-                "registration mismatch: requested_repo: {:?}, vs. repo: {:?}",
-                requested_rk, self.repo_key
-            )));
-        }
+        self.repos
+            .get(&requested_rk.owner_name, &requested_rk.repo_name)?;
         Ok(Response::new(RegisterResponse::default()))
     }
 
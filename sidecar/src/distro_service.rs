@@ -13,7 +13,7 @@ use crate::{
         GetRepositoryVersionResponse, RegisterClientRequest, RegisterClientResponse, RepositoryKey,
         SendFlagEvaluationMetricsRequest, SendFlagEvaluationMetricsResponse,
     },
-    store::Store,
+    repos::{Repo, Repos},
     types::{override_api_key, ConnectionCredentials, APIKEY},
 };
 
@@ -22,12 +22,23 @@ use crate::{
 pub struct Service {
     pub distro_client:
         DistributionServiceClient<hyper::Client<HttpsConnector<HttpConnector>, BoxBody>>,
-    pub conn_creds: Option<ConnectionCredentials>,
-    pub store: Arc<Store>,
-    pub repo_key: RepositoryKey,
+    pub repos: Arc<Repos>,
     pub sidecar_version: String,
 }
 
+impl Service {
+    fn repo(&self, repo_key: Option<&RepositoryKey>) -> Result<&Arc<Repo>, Status> {
+        let repo_key = repo_key.ok_or_else(|| Status::invalid_argument("no repo key provided"))?;
+        self.repos.get(&repo_key.owner_name, &repo_key.repo_name)
+    }
+
+    // The credentials for requests that aren't for a particular repository. Every
+    // repository is registered with the same api key, so any of them will do.
+    fn sidecar_creds(&self) -> Option<ConnectionCredentials> {
+        self.repos.iter().find_map(|repo| repo.conn_creds.clone())
+    }
+}
+
 const TIERED_PREFIX: &str = "tiered";
 
 #[tonic::async_trait]
@@ -36,44 +47,20 @@ impl DistributionService for Service {
         &self,
         request: Request<GetRepositoryVersionRequest>,
     ) -> Result<tonic::Response<GetRepositoryVersionResponse>, tonic::Status> {
-        let requested_rk = request
-            .get_ref()
-            .repo_key
-            .as_ref()
-            .ok_or_else(|| Status::invalid_argument("no repo key provided"))?;
-        if self.repo_key.owner_name != requested_rk.owner_name
-            || self.repo_key.repo_name != requested_rk.repo_name
-        {
-            return Err(Status::invalid_argument(format!(
-                "registration mismatch: requested_repo: {:?}, vs. repo: {:?}",
-                requested_rk, self.repo_key
-            )));
-        }
+        let repo = self.repo(request.get_ref().repo_key.as_ref())?;
         return Ok(Response::new(GetRepositoryVersionResponse {
-            commit_sha: self.store.get_version_local(),
+            commit_sha: repo.store.get_version_local(),
         }));
     }
     async fn get_repository_contents(
         &self,
         request: Request<GetRepositoryContentsRequest>,
     ) -> Result<tonic::Response<GetRepositoryContentsResponse>, tonic::Status> {
-        let requested_rk = request
-            .get_ref()
-            .repo_key
-            .as_ref()
-            .ok_or_else(|| Status::invalid_argument("no repo key provided"))?;
-        if self.repo_key.owner_name != requested_rk.owner_name
-            || self.repo_key.repo_name != requested_rk.repo_name
-        {
-            return Err(Status::invalid_argument(format!(
-                "registration mismatch: requested_repo: {:?}, vs. repo: {:?}",
-                requested_rk, self.repo_key
-            )));
-        }
+        let repo = self.repo(request.get_ref().repo_key.as_ref())?;
         let request = request.into_inner();
-        let (version, namespaces, file_descriptor_set) = self
+        let (version, namespaces, file_descriptor_set) = repo
             .store
-            .get_repo_contents_local(&request.namespace_name, &request.feature_name);
+            .get_repo_contents_local(&request.namespace_name, &request.feature_name)?;
         Ok(Response::new(GetRepositoryContentsResponse {
             namespaces,
             commit_sha: version,
@@ -86,7 +73,8 @@ impl DistributionService for Service {
         request: tonic::Request<SendFlagEvaluationMetricsRequest>,
     ) -> std::result::Result<tonic::Response<SendFlagEvaluationMetricsResponse>, tonic::Status>
     {
-        if self.conn_creds.is_none() && request.metadata().get(APIKEY).is_none() {
+        let conn_creds = self.sidecar_creds();
+        if conn_creds.is_none() && request.metadata().get(APIKEY).is_none() {
             return Ok(tonic::Response::new(
                 SendFlagEvaluationMetricsResponse::default(),
             ));
@@ -98,7 +86,7 @@ impl DistributionService for Service {
         self.distro_client
             .clone()
             .to_owned()
-            .send_flag_evaluation_metrics(override_api_key(new_req, &self.conn_creds))
+            .send_flag_evaluation_metrics(override_api_key(new_req, &conn_creds))
             .await
     }
 
@@ -106,20 +94,8 @@ impl DistributionService for Service {
         &self,
         request: tonic::Request<RegisterClientRequest>,
     ) -> std::result::Result<tonic::Response<RegisterClientResponse>, tonic::Status> {
-        let requested_rk = request
-            .get_ref()
-            .repo_key
-            .as_ref()
-            .ok_or_else(|| Status::invalid_argument("no repo key provided"))?;
-        if self.repo_key.owner_name != requested_rk.owner_name
-            || self.repo_key.repo_name != requested_rk.repo_name
-        {
-            return Err(Status::invalid_argument(format!(
-                "registration mismatch: requested_repo: {:?}, vs. repo: {:?}",
-                requested_rk, self.repo_key
-            )));
-        }
-        if self.conn_creds.is_none() && request.metadata().get(APIKEY).is_none() {
+        let repo = self.repo(request.get_ref().repo_key.as_ref())?;
+        if repo.conn_creds.is_none() && request.metadata().get(APIKEY).is_none() {
             return Ok(tonic::Response::new(RegisterClientResponse::default()));
         }
         let mut register_request = request.get_ref().clone();
@@ -138,7 +114,7 @@ impl DistributionService for Service {
         self.distro_client
             .clone()
             .to_owned()
-            .register_client(override_api_key(new_req, &self.conn_creds))
+            .register_client(override_api_key(new_req, &repo.conn_creds))
             .await
     }
 
@@ -146,7 +122,8 @@ impl DistributionService for Service {
         &self,
         request: tonic::Request<DeregisterClientRequest>,
     ) -> std::result::Result<tonic::Response<DeregisterClientResponse>, tonic::Status> {
-        if self.conn_creds.is_none() && request.metadata().get(APIKEY).is_none() {
+        let conn_creds = self.sidecar_creds();
+        if conn_creds.is_none() && request.metadata().get(APIKEY).is_none() {
             return Ok(tonic::Response::new(DeregisterClientResponse::default()));
         }
         let mut req = tonic::Request::new(request.get_ref().clone());
@@ -155,7 +132,7 @@ impl DistributionService for Service {
         self.distro_client
             .clone()
             .to_owned()
-            .deregister_client(override_api_key(req, &self.conn_creds))
+            .deregister_client(override_api_key(req, &conn_creds))
             .await
     }
 
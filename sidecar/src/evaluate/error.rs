@@ -0,0 +1,152 @@
+use std::fmt;
+
+use crate::gen::sdk::lekko::client::v1beta1::value::Kind as LekkoKind;
+
+// EvalError is an error encountered while evaluating a feature. The services that
+// evaluate features for their clients convert it to a Status, through
+// metrics::evaluation_error.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EvalError {
+    // The feature has no tree.
+    MissingTree,
+    // The feature's tree has no default value.
+    MissingDefault,
+    // Part of a rule is missing or malformed, e.g. an atom without a comparison
+    // value. The message says which part.
+    MalformedRule(&'static str),
+    // The operator is unspecified, or can't be used where it appears.
+    UnsupportedOperator(&'static str),
+    // A context value can't be compared with the rule value, or the rule value
+    // can't be used with its operator.
+    TypeMismatch {
+        key: String,
+        expected: String,
+        actual: String,
+    },
+    // Bucketing is only supported for string, int and double context values.
+    BucketUnsupportedType {
+        key: String,
+        actual: String,
+    },
+    // A call to a function this version of the sidecar doesn't know about.
+    UnknownFunction,
+    // The context value for the key has no kind.
+    EmptyContextValue {
+        key: String,
+    },
+    // evaluate_to was used without a way to look up other configs.
+    MissingResolver,
+    // The config referenced by evaluate_to doesn't exist.
+    ConfigNotFound {
+        namespace: String,
+        name: String,
+    },
+    // evaluate_to referenced a config that is already being evaluated. The chain
+    // is the list of configs being evaluated.
+    Cycle {
+        chain: String,
+    },
+    // evaluate_to chains were nested more than MAX_EVALUATION_DEPTH deep.
+    MaxDepthExceeded {
+        chain: String,
+    },
+    // The value of a config referenced by evaluate_to can't be compared.
+    InvalidConfigValue(String),
+}
+
+impl EvalError {
+    pub(crate) fn type_mismatch(
+        key: &str,
+        expected: impl Into<String>,
+        actual: impl Into<String>,
+    ) -> Self {
+        Self::TypeMismatch {
+            key: key.to_owned(),
+            expected: expected.into(),
+            actual: actual.into(),
+        }
+    }
+
+    // A short, stable name for the kind of error, e.g. for metric labels.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::MissingTree => "missing_tree",
+            Self::MissingDefault => "missing_default",
+            Self::MalformedRule(_) => "malformed_rule",
+            Self::UnsupportedOperator(_) => "unsupported_operator",
+            Self::TypeMismatch { .. } => "type_mismatch",
+            Self::BucketUnsupportedType { .. } => "bucket_unsupported_type",
+            Self::UnknownFunction => "unknown_function",
+            Self::EmptyContextValue { .. } => "empty_context_value",
+            Self::MissingResolver => "missing_resolver",
+            Self::ConfigNotFound { .. } => "config_not_found",
+            Self::Cycle { .. } => "cycle",
+            Self::MaxDepthExceeded { .. } => "max_depth_exceeded",
+            Self::InvalidConfigValue(_) => "invalid_config_value",
+        }
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingTree => write!(f, "empty tree"),
+            Self::MissingDefault => write!(f, "empty default value"),
+            Self::MalformedRule(what) => write!(f, "{what}"),
+            Self::UnsupportedOperator(op) => write!(f, "unsupported operator {op}"),
+            Self::TypeMismatch {
+                key,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "type mismatch for context key {key:?}: expected {expected}, got {actual}"
+            ),
+            Self::BucketUnsupportedType { key, actual } => {
+                write!(
+                    f,
+                    "unsupported type for bucket on context key {key:?}: {actual}"
+                )
+            }
+            Self::UnknownFunction => write!(f, "unknown function"),
+            Self::EmptyContextValue { key } => write!(f, "empty ctx value kind for {key:?}"),
+            Self::MissingResolver => write!(f, "no feature resolver available for evaluate_to"),
+            Self::ConfigNotFound { namespace, name } => write!(
+                f,
+                "config {namespace}/{name} referenced by evaluate_to not found"
+            ),
+            Self::Cycle { chain } => write!(f, "cycle detected in evaluate_to: {chain}"),
+            Self::MaxDepthExceeded { chain } => write!(
+                f,
+                "evaluate_to exceeded max depth of {}: {chain}",
+                super::functions::MAX_EVALUATION_DEPTH
+            ),
+            Self::InvalidConfigValue(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+// The name of a context value's type, for error messages.
+pub(crate) fn kind_name(kind: &LekkoKind) -> &'static str {
+    match kind {
+        LekkoKind::BoolValue(_) => "bool",
+        LekkoKind::IntValue(_) => "int",
+        LekkoKind::DoubleValue(_) => "double",
+        LekkoKind::StringValue(_) => "string",
+    }
+}
+
+// The name of a rule value's type, for error messages.
+pub(crate) fn rule_kind_name(kind: &prost_types::value::Kind) -> &'static str {
+    use prost_types::value::Kind;
+    match kind {
+        Kind::NullValue(_) => "null",
+        Kind::NumberValue(_) => "number",
+        Kind::StringValue(_) => "string",
+        Kind::BoolValue(_) => "bool",
+        Kind::StructValue(_) => "struct",
+        Kind::ListValue(_) => "list",
+    }
+}
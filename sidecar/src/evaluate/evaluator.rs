@@ -1,36 +1,99 @@
 use std::collections::HashMap;
 
 use prost_types::Any;
-use tonic::Status;
 
 use crate::{
-    gen::cli::lekko::feature::v1beta1::{Constraint, Feature},
-    gen::sdk::lekko::client::v1beta1::Value,
+    gen::cli::lekko::feature::v1beta1::Constraint, gen::sdk::lekko::client::v1beta1::Value,
+    gen::sidecar::lekko::sidecar::v1beta1::CheckTrace, types::TypeMismatchPolicy,
 };
 
-use super::rules_v3::check_rule as check_rule_v3;
+use super::error::EvalError;
+use super::plan::{CompiledConstraint, CompiledFeature};
+use super::rules::check_rule;
+use super::trace::Tracer;
 
 // Meta-level context for evaluation, separate from the context given
 // by users as part of the evaluation request.
 // Information required to evaluate rules but not part of the feature's
 // context.
-pub struct EvalContext {
+#[derive(Default)]
+pub struct EvalContext<'a> {
     pub namespace: String,
     pub feature_name: String,
+    // Used to look up other features that are referenced during evaluation,
+    // e.g. by evaluate_to. If unset, such references fail to evaluate.
+    pub resolver: Option<&'a dyn FeatureResolver>,
+    // The evaluation that referenced this feature, if this is a nested evaluation.
+    pub parent: Option<&'a EvalContext<'a>>,
+    // If set, records how the evaluation arrived at its result.
+    pub tracer: Option<&'a Tracer>,
+    pub type_mismatch_policy: TypeMismatchPolicy,
+}
+
+impl EvalContext<'_> {
+    // Number of nested evaluations that led to this one. The top-level evaluation
+    // has a depth of 0.
+    pub fn depth(&self) -> usize {
+        self.parent.map_or(0, |p| p.depth() + 1)
+    }
+
+    // Whether the given feature is already being evaluated further up the chain,
+    // including by this evaluation.
+    pub fn is_evaluating(&self, namespace: &str, feature_name: &str) -> bool {
+        (self.namespace == namespace && self.feature_name == feature_name)
+            || self
+                .parent
+                .map_or(false, |p| p.is_evaluating(namespace, feature_name))
+    }
+
+    // Records a check made while evaluating a rule, if this evaluation is traced.
+    // The check is only built if it is going to be recorded.
+    pub fn trace_check(&self, check: impl FnOnce() -> CheckTrace) {
+        if let Some(tracer) = self.tracer {
+            tracer.record_check(check());
+        }
+    }
+
+    // Human readable chain of features being evaluated, outermost first.
+    pub fn chain(&self) -> String {
+        let current = format!("{}/{}", self.namespace, self.feature_name);
+        match self.parent {
+            Some(p) => format!("{} -> {current}", p.chain()),
+            None => current,
+        }
+    }
+}
+
+// FeatureResolver gives the evaluator access to the other features in the
+// repository. Implementations should serve every lookup made during a single
+// evaluation from the same snapshot of the repository.
+pub trait FeatureResolver {
+    fn resolve(&self, namespace: &str, feature_name: &str) -> Option<&CompiledFeature>;
 }
 
 // Performs evaluation of the feature tree using the given context.
 pub fn evaluate(
-    feature: &Feature,
+    feature: &CompiledFeature,
     context: &HashMap<String, Value>,
     eval_context: &EvalContext,
-) -> Result<(Any, Vec<usize>), Status> {
+) -> Result<(Any, Vec<usize>), EvalError> {
     let tree = feature
+        .proto()
         .tree
         .as_ref()
-        .ok_or_else(|| Status::internal("empty tree"))?;
-    for (i, constraint) in tree.constraints.iter().enumerate() {
-        if let Some((child_val, child_path)) = traverse(constraint, context, eval_context)? {
+        .ok_or(EvalError::MissingTree)?;
+    // Path to the constraint currently being traversed, only used for tracing.
+    let mut path = Vec::new();
+    for (i, (constraint, compiled)) in tree
+        .constraints
+        .iter()
+        .zip(&feature.constraints)
+        .enumerate()
+    {
+        path.push(i);
+        let passed = traverse(constraint, compiled, context, eval_context, &mut path)?;
+        path.pop();
+        if let Some((child_val, child_path)) = passed {
             if let Some(some_child_val) = child_val {
                 return Ok((
                     some_child_val,
@@ -42,9 +105,10 @@ pub fn evaluate(
         // Child evaluation did not pass, continue iterating
     }
     Ok((
-        tree.default
+        feature
+            .default
             .as_ref()
-            .ok_or_else(|| Status::internal("empty default value"))?
+            .ok_or(EvalError::MissingDefault)?
             .clone(),
         Vec::new(),
     ))
@@ -56,25 +120,36 @@ pub fn evaluate(
 type PassedEvaluation = (Option<Any>, Vec<usize>);
 
 // traverse is a recursive function that performs tree-traversal on the feature tree,
-// evaluating the rules along the way.
+// evaluating the rules along the way. The compiled constraint is the evaluation plan
+// for the given constraint.
 // The option of PassedEvaluation denotes whether the traversal of this constraint resulted
 // in a pass.
 fn traverse(
     constraint: &Constraint,
+    compiled: &CompiledConstraint,
     context: &HashMap<String, Value>,
     eval_context: &EvalContext,
-) -> Result<Option<PassedEvaluation>, Status> {
-    let passes = match &constraint.rule_ast_new {
-        Some(ast) => check_rule_v3(ast, context, eval_context)?,
-        None => return Err(Status::internal("empty rule v3")),
-    };
-    if !passes {
+    path: &mut Vec<usize>,
+) -> Result<Option<PassedEvaluation>, EvalError> {
+    let result = check_rule(&compiled.rule, context, eval_context);
+    let trace_handle = eval_context
+        .tracer
+        .map(|t| t.record_constraint(path, constraint, compiled.value.is_some(), &result));
+    if !result? {
         // if the rule fails, we avoid further traversal
         return Ok(None);
     }
     // rule passed
-    for (i, child) in constraint.constraints.iter().enumerate() {
-        if let Some((child_val, child_path)) = traverse(child, context, eval_context)? {
+    for (i, (child, compiled_child)) in constraint
+        .constraints
+        .iter()
+        .zip(&compiled.constraints)
+        .enumerate()
+    {
+        path.push(i);
+        let passed = traverse(child, compiled_child, context, eval_context, path)?;
+        path.pop();
+        if let Some((child_val, child_path)) = passed {
             if let Some(some_child_val) = child_val {
                 return Ok(Some((
                     Some(some_child_val),
@@ -85,5 +160,144 @@ fn traverse(
         }
         // Child evaluation did not pass, continue iterating
     }
-    Ok(Some((constraint.value.clone(), Vec::new())))
+    if compiled.value.is_none() {
+        if let (Some(tracer), Some(handle)) = (eval_context.tracer, trace_handle) {
+            tracer.mark_fell_back(handle);
+        }
+    }
+    Ok(Some((compiled.value.clone(), Vec::new())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluate::plan::Interner;
+    use crate::gen::cli::lekko::feature::v1beta1::{Any as LekkoAny, Feature, Tree};
+    use crate::gen::cli::lekko::rules::v1beta3::{rule::Rule as RuleKind, Rule};
+
+    const INT_TYPE_URL: &str = "type.googleapis.com/google.protobuf.Int64Value";
+
+    fn int_any(i: i64) -> Any {
+        Any {
+            type_url: String::from(INT_TYPE_URL),
+            value: crate::types::to_any(&i).value,
+        }
+    }
+
+    fn int_lekko_any(i: i64) -> LekkoAny {
+        LekkoAny {
+            type_url: String::from(INT_TYPE_URL),
+            value: crate::types::to_any(&i).value,
+        }
+    }
+
+    fn bool_rule(b: bool) -> Option<Rule> {
+        Some(Rule {
+            rule: Some(RuleKind::BoolConst(b)),
+            ..Default::default()
+        })
+    }
+
+    fn evaluate_tree(tree: Tree) -> Result<(Any, Vec<usize>), EvalError> {
+        let feature = Feature {
+            key: String::from("feature"),
+            tree: Some(tree),
+            ..Default::default()
+        };
+        let feature = CompiledFeature::new("ns", feature, &mut Interner::default());
+        evaluate(&feature, &HashMap::new(), &EvalContext::default())
+    }
+
+    #[test]
+    fn test_default_new() {
+        let tree = Tree {
+            default_new: Some(int_lekko_any(1)),
+            ..Default::default()
+        };
+        assert_eq!(evaluate_tree(tree).unwrap(), (int_any(1), vec![]));
+        // The new field is preferred when both are set.
+        let tree = Tree {
+            default: Some(int_any(1)),
+            default_new: Some(int_lekko_any(2)),
+            ..Default::default()
+        };
+        assert_eq!(evaluate_tree(tree).unwrap(), (int_any(2), vec![]));
+        let tree = Tree {
+            default: Some(int_any(1)),
+            ..Default::default()
+        };
+        assert_eq!(evaluate_tree(tree).unwrap(), (int_any(1), vec![]));
+        assert_eq!(
+            evaluate_tree(Tree::default()).unwrap_err(),
+            EvalError::MissingDefault
+        );
+    }
+
+    #[test]
+    fn test_mixed_values() {
+        let tree = |constraints| Tree {
+            default_new: Some(int_lekko_any(0)),
+            constraints,
+            ..Default::default()
+        };
+        // A constraint with only the old value.
+        let old = Constraint {
+            rule_ast_new: bool_rule(true),
+            value: Some(int_any(1)),
+            ..Default::default()
+        };
+        // A constraint with only the new value.
+        let new = Constraint {
+            rule_ast_new: bool_rule(true),
+            value_new: Some(int_lekko_any(2)),
+            ..Default::default()
+        };
+        let both = Constraint {
+            rule_ast_new: bool_rule(true),
+            value: Some(int_any(3)),
+            value_new: Some(int_lekko_any(4)),
+            ..Default::default()
+        };
+        assert_eq!(
+            evaluate_tree(tree(vec![old.clone()])).unwrap(),
+            (int_any(1), vec![0])
+        );
+        assert_eq!(
+            evaluate_tree(tree(vec![new.clone()])).unwrap(),
+            (int_any(2), vec![0])
+        );
+        assert_eq!(
+            evaluate_tree(tree(vec![both])).unwrap(),
+            (int_any(4), vec![0])
+        );
+        // Nested constraints fall back to the nearest value, whichever field it's in.
+        let nested = |parent: &Constraint, child: Constraint| Constraint {
+            constraints: vec![child],
+            ..parent.clone()
+        };
+        let no_value = Constraint {
+            rule_ast_new: bool_rule(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            evaluate_tree(tree(vec![nested(&old, new.clone())])).unwrap(),
+            (int_any(2), vec![0, 0])
+        );
+        assert_eq!(
+            evaluate_tree(tree(vec![nested(&new, no_value.clone())])).unwrap(),
+            (int_any(2), vec![0])
+        );
+        assert_eq!(
+            evaluate_tree(tree(vec![nested(&old, no_value.clone())])).unwrap(),
+            (int_any(1), vec![0])
+        );
+        assert_eq!(
+            evaluate_tree(tree(vec![nested(&no_value, old)])).unwrap(),
+            (int_any(1), vec![0, 0])
+        );
+        assert_eq!(
+            evaluate_tree(tree(vec![no_value])).unwrap(),
+            (int_any(0), vec![])
+        );
+    }
 }
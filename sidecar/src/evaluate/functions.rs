@@ -1,62 +1,181 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
-use tonic::Status;
-use xxhash_rust::xxh32::xxh32;
+use prost_types::{value::Kind, Any};
+use xxhash_rust::xxh32::Xxh32;
 
-use super::evaluator::EvalContext;
-use crate::gen::cli::lekko::rules::v1beta3::call_expression::Bucket;
+use super::error::{kind_name, EvalError};
+use super::evaluator::{evaluate, EvalContext};
 use crate::gen::sdk::lekko::client::v1beta1::{value::Kind as LekkoKind, Value as LekkoValue};
+use crate::types::from_any;
 
+// Maximum number of nested evaluations through evaluate_to. Chains of configs
+// this long are almost certainly a mistake, and we don't want to overflow the stack.
+pub const MAX_EVALUATION_DEPTH: usize = 16;
+
+// Bucket checks whether the context value falls in the bucket defined by threshold.
 // If the hashed feature value % 100 <= threshold, it fits in the "bucket".
 // In reality, we internally store the threshold as an integer in [0,100000]
 // to account for up to 3 decimal places.
 // The feature value is salted using the namespace, feature name, and context key.
-pub fn bucket(
-    bucket_f: &Bucket,
+pub struct Bucket {
+    pub context_key: Arc<str>,
+    pub threshold: u32,
+    // The namespace, feature name and context key, which are the same for every
+    // evaluation of this bucket.
+    salt_prefix: Vec<u8>,
+}
+
+impl Bucket {
+    pub fn new(namespace: &str, feature_name: &str, context_key: Arc<str>, threshold: u32) -> Self {
+        let salt_prefix = [
+            namespace.as_bytes(),
+            feature_name.as_bytes(),
+            context_key.as_bytes(),
+        ]
+        .concat();
+        Self {
+            context_key,
+            threshold,
+            salt_prefix,
+        }
+    }
+
+    pub fn check(&self, context: &HashMap<String, LekkoValue>) -> Result<bool, EvalError> {
+        // If key is missing in context map, evaluate to false - move to next rule
+        let Some(ctx_value) = context.get(&*self.context_key) else {
+            return Ok(false);
+        };
+        let ctx_kind = ctx_value
+            .kind
+            .as_ref()
+            .ok_or_else(|| EvalError::EmptyContextValue {
+                key: self.context_key.to_string(),
+            })?;
+
+        let mut hasher = Xxh32::new(0);
+        hasher.update(&self.salt_prefix);
+        match ctx_kind {
+            LekkoKind::StringValue(value) => hasher.update(value.as_bytes()),
+            LekkoKind::IntValue(value) => hasher.update(&value.to_be_bytes()),
+            LekkoKind::DoubleValue(value) => hasher.update(&value.to_be_bytes()),
+            _ => {
+                return Err(EvalError::BucketUnsupportedType {
+                    key: self.context_key.to_string(),
+                    actual: kind_name(ctx_kind).to_owned(),
+                });
+            }
+        };
+        let hash = hasher.digest();
+
+        Ok(hash % 100000 <= self.threshold)
+    }
+}
+
+// EvaluateTo checks whether another config evaluates to the given value.
+pub struct EvaluateTo {
+    // The referenced config, either "config" or "namespace/config".
+    pub config_name: String,
+    pub config_value: Option<prost_types::Value>,
+}
+
+// Evaluates the referenced config with the same context, and checks whether
+// it evaluates to the given value. The config is looked up in the namespace of
+// the feature being evaluated, unless config_name is of the form "namespace/config".
+pub fn evaluate_to(
+    evaluate_to_f: &EvaluateTo,
     context: &HashMap<String, LekkoValue>,
     eval_context: &EvalContext,
-) -> Result<bool, Status> {
-    let ctx_key = &bucket_f.context_key;
-    let present = context.contains_key(ctx_key);
-    // If key is missing in context map, evaluate to false - move to next rule
-    if !present {
-        return Ok(false);
-    }
-    let ctx_kind = context
-        .get(ctx_key)
-        .ok_or_else(|| Status::internal("empty ctx value"))?
-        .kind
+) -> Result<bool, EvalError> {
+    let (namespace, config_name) = match evaluate_to_f.config_name.split_once('/') {
+        Some((namespace, config_name)) => (namespace, config_name),
+        None => (
+            eval_context.namespace.as_str(),
+            evaluate_to_f.config_name.as_str(),
+        ),
+    };
+    let expected = evaluate_to_f
+        .config_value
         .as_ref()
-        .ok_or_else(|| Status::internal("empty ctx value kind"))?;
-
-    let value_bytes = match ctx_kind {
-        LekkoKind::StringValue(value) => value.as_bytes().to_owned(),
-        LekkoKind::IntValue(value) => value.to_be_bytes().to_vec(),
-        LekkoKind::DoubleValue(value) => value.to_be_bytes().to_vec(),
-        _ => {
-            return Err(Status::internal("unsupported type for bucket"));
-        }
+        .and_then(|v| v.kind.as_ref())
+        .ok_or(EvalError::MalformedRule("empty evaluate_to config value"))?;
+    if eval_context.is_evaluating(namespace, config_name) {
+        return Err(EvalError::Cycle {
+            chain: format!("{} -> {namespace}/{config_name}", eval_context.chain()),
+        });
+    }
+    if eval_context.depth() >= MAX_EVALUATION_DEPTH {
+        return Err(EvalError::MaxDepthExceeded {
+            chain: eval_context.chain(),
+        });
+    }
+    let feature = eval_context
+        .resolver
+        .ok_or(EvalError::MissingResolver)?
+        .resolve(namespace, config_name)
+        .ok_or_else(|| EvalError::ConfigNotFound {
+            namespace: namespace.to_owned(),
+            name: config_name.to_owned(),
+        })?;
+    let nested_context = EvalContext {
+        namespace: namespace.to_owned(),
+        feature_name: feature.proto().key.to_owned(),
+        resolver: eval_context.resolver,
+        parent: Some(eval_context),
+        tracer: None,
+        type_mismatch_policy: eval_context.type_mismatch_policy,
     };
+    let (value, _) = evaluate(feature, context, &nested_context)?;
+    any_equals(&value, expected)
+}
 
-    let salted_bytes = [
-        eval_context.namespace.as_bytes(),
-        eval_context.feature_name.as_bytes(),
-        ctx_key.as_bytes(),
-        value_bytes.as_slice(),
-    ]
-    .concat();
-    let hash = xxh32(salted_bytes.as_slice(), 0);
-
-    Ok(hash % 100000 <= bucket_f.threshold)
+// Compares the value of an evaluated config with a rule value. Only primitive
+// config types can be compared.
+fn any_equals(value: &Any, expected: &Kind) -> Result<bool, EvalError> {
+    let decode_err = |e: prost::DecodeError| EvalError::InvalidConfigValue(e.to_string());
+    let type_name = value.type_url.rsplit('/').next().unwrap_or_default();
+    match (type_name, expected) {
+        ("google.protobuf.BoolValue", Kind::BoolValue(b)) => {
+            Ok(from_any::<bool>(value).map_err(decode_err)? == *b)
+        }
+        ("google.protobuf.Int64Value", Kind::NumberValue(n)) => {
+            Ok(from_any::<i64>(value).map_err(decode_err)? as f64 == *n)
+        }
+        ("google.protobuf.DoubleValue", Kind::NumberValue(n)) => {
+            Ok(from_any::<f64>(value).map_err(decode_err)? == *n)
+        }
+        ("google.protobuf.StringValue", Kind::StringValue(s)) => {
+            Ok(from_any::<String>(value).map_err(decode_err)? == *s)
+        }
+        (
+            "google.protobuf.BoolValue"
+            | "google.protobuf.Int64Value"
+            | "google.protobuf.DoubleValue"
+            | "google.protobuf.StringValue",
+            _,
+        ) => Err(EvalError::InvalidConfigValue(format!(
+            "type mismatch: cannot compare config of type {type_name} with {expected:?}"
+        ))),
+        _ => Err(EvalError::InvalidConfigValue(format!(
+            "unsupported config type for evaluate_to: {type_name}"
+        ))),
+    }
 }
 
 #[allow(clippy::approx_constant)]
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::evaluate::evaluator::FeatureResolver;
+    use crate::evaluate::plan::{CompiledFeature, Interner};
+    use crate::gen::cli::lekko::feature::v1beta1::{Constraint, Feature, Tree};
+    use crate::gen::cli::lekko::rules::v1beta3::{
+        call_expression::{Bucket, EvaluateTo, Function},
+        rule::Rule as RuleKind,
+        CallExpression, Rule,
+    };
 
     struct Setup {
-        eval_contexts: Vec<EvalContext>,
+        eval_contexts: Vec<EvalContext<'static>>,
     }
 
     impl Setup {
@@ -66,16 +185,32 @@ mod tests {
                     EvalContext {
                         namespace: String::from("ns_1"),
                         feature_name: String::from("feature_1"),
+                        ..Default::default()
                     },
                     EvalContext {
                         namespace: String::from("ns_2"),
                         feature_name: String::from("feature_2"),
+                        ..Default::default()
                     },
                 ],
             }
         }
     }
 
+    fn bucket(
+        bucket_f: &Bucket,
+        context: &HashMap<String, LekkoValue>,
+        eval_context: &EvalContext,
+    ) -> Result<bool, EvalError> {
+        super::Bucket::new(
+            &eval_context.namespace,
+            &eval_context.feature_name,
+            Arc::from(bucket_f.context_key.as_str()),
+            bucket_f.threshold,
+        )
+        .check(context)
+    }
+
     // NOTE: to test consistency of the hashing/bucketing algorithms cross-platform
     // test cases (data and expected evaluation results) should be identical
     fn assert_bucket<T: std::fmt::Display>(
@@ -283,4 +418,195 @@ mod tests {
             }
         }
     }
+
+    // Features keyed by (namespace, name), for testing evaluate_to.
+    struct TestResolver(HashMap<(String, String), CompiledFeature>);
+
+    impl FeatureResolver for TestResolver {
+        fn resolve(&self, namespace: &str, feature_name: &str) -> Option<&CompiledFeature> {
+            self.0.get(&(namespace.to_owned(), feature_name.to_owned()))
+        }
+    }
+
+    fn bool_any(b: bool) -> Any {
+        Any {
+            type_url: String::from("type.googleapis.com/google.protobuf.BoolValue"),
+            value: crate::types::to_any(&b).value,
+        }
+    }
+
+    fn evaluate_to_rule(config_name: &str, config_value: bool) -> Rule {
+        Rule {
+            rule: Some(RuleKind::CallExpression(CallExpression {
+                function: Some(Function::EvaluateTo(EvaluateTo {
+                    config_name: config_name.to_owned(),
+                    config_value: Some(prost_types::Value {
+                        kind: Some(Kind::BoolValue(config_value)),
+                    }),
+                })),
+            })),
+            ..Default::default()
+        }
+    }
+
+    // A bool feature that is false by default, and true if the rule passes.
+    fn bool_feature(key: &str, rule: Rule) -> Feature {
+        Feature {
+            key: key.to_owned(),
+            tree: Some(Tree {
+                default: Some(bool_any(false)),
+                constraints: vec![Constraint {
+                    value: Some(bool_any(true)),
+                    rule_ast_new: Some(rule),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn const_feature(key: &str, value: bool) -> Feature {
+        bool_feature(
+            key,
+            Rule {
+                rule: Some(RuleKind::BoolConst(value)),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn resolver(features: Vec<(&str, Feature)>) -> TestResolver {
+        let mut interner = Interner::default();
+        TestResolver(
+            features
+                .into_iter()
+                .map(|(ns, f)| {
+                    (
+                        (ns.to_owned(), f.key.clone()),
+                        CompiledFeature::new(ns, f, &mut interner),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn evaluate_feature(
+        resolver: &TestResolver,
+        namespace: &str,
+        key: &str,
+    ) -> Result<bool, EvalError> {
+        let feature = resolver.resolve(namespace, key).unwrap();
+        let eval_context = EvalContext {
+            namespace: namespace.to_owned(),
+            feature_name: key.to_owned(),
+            resolver: Some(resolver),
+            ..Default::default()
+        };
+        let (value, _) = evaluate(feature, &HashMap::new(), &eval_context)?;
+        Ok(from_any::<bool>(&value).unwrap())
+    }
+
+    #[test]
+    fn test_evaluate_to() {
+        let resolver = resolver(vec![
+            ("ns_1", const_feature("enabled", true)),
+            ("ns_1", const_feature("disabled", false)),
+            (
+                "ns_1",
+                bool_feature("if_enabled", evaluate_to_rule("enabled", true)),
+            ),
+            (
+                "ns_1",
+                bool_feature("if_disabled", evaluate_to_rule("disabled", true)),
+            ),
+            (
+                "ns_2",
+                bool_feature("cross_ns", evaluate_to_rule("ns_1/enabled", true)),
+            ),
+            (
+                "ns_2",
+                bool_feature("missing", evaluate_to_rule("enabled", true)),
+            ),
+        ]);
+        assert!(evaluate_feature(&resolver, "ns_1", "if_enabled").unwrap());
+        assert!(!evaluate_feature(&resolver, "ns_1", "if_disabled").unwrap());
+        assert!(evaluate_feature(&resolver, "ns_2", "cross_ns").unwrap());
+        // the referenced config is looked up in the same namespace by default
+        assert!(evaluate_feature(&resolver, "ns_2", "missing").is_err());
+    }
+
+    #[test]
+    fn test_evaluate_to_type_mismatch() {
+        let mut rule = evaluate_to_rule("enabled", true);
+        if let Some(RuleKind::CallExpression(CallExpression {
+            function: Some(Function::EvaluateTo(f)),
+        })) = rule.rule.as_mut()
+        {
+            f.config_value = Some(prost_types::Value {
+                kind: Some(Kind::StringValue(String::from("true"))),
+            });
+        }
+        let resolver = resolver(vec![
+            ("ns_1", const_feature("enabled", true)),
+            ("ns_1", bool_feature("feature", rule)),
+        ]);
+        assert!(evaluate_feature(&resolver, "ns_1", "feature").is_err());
+    }
+
+    #[test]
+    fn test_evaluate_to_without_resolver() {
+        let feature = CompiledFeature::new(
+            "ns_1",
+            bool_feature("feature", evaluate_to_rule("other", true)),
+            &mut Interner::default(),
+        );
+        let eval_context = EvalContext {
+            namespace: String::from("ns_1"),
+            feature_name: String::from("feature"),
+            ..Default::default()
+        };
+        assert!(evaluate(&feature, &HashMap::new(), &eval_context).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_to_cycle() {
+        let resolver = resolver(vec![
+            ("ns_1", bool_feature("self", evaluate_to_rule("self", true))),
+            ("ns_1", bool_feature("a", evaluate_to_rule("b", true))),
+            ("ns_1", bool_feature("b", evaluate_to_rule("a", true))),
+        ]);
+        let err = evaluate_feature(&resolver, "ns_1", "self").unwrap_err();
+        assert!(matches!(err, EvalError::Cycle { .. }), "{err:?}");
+        let err = evaluate_feature(&resolver, "ns_1", "a").unwrap_err();
+        assert_eq!(
+            err,
+            EvalError::Cycle {
+                chain: String::from("ns_1/a -> ns_1/b -> ns_1/a")
+            }
+        );
+    }
+
+    #[test]
+    fn test_evaluate_to_max_depth() {
+        // A chain of features, each referencing the next one.
+        let chain = |len: usize| {
+            resolver(
+                (0..len)
+                    .map(|i| {
+                        let key = format!("f{i}");
+                        if i + 1 == len {
+                            ("ns_1", const_feature(&key, true))
+                        } else {
+                            let next = format!("f{}", i + 1);
+                            ("ns_1", bool_feature(&key, evaluate_to_rule(&next, true)))
+                        }
+                    })
+                    .collect(),
+            )
+        };
+        assert!(evaluate_feature(&chain(MAX_EVALUATION_DEPTH + 1), "ns_1", "f0").unwrap());
+        let err = evaluate_feature(&chain(MAX_EVALUATION_DEPTH + 2), "ns_1", "f0").unwrap_err();
+        assert!(matches!(err, EvalError::MaxDepthExceeded { .. }), "{err:?}");
+    }
 }
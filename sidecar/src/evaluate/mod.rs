@@ -1,3 +1,8 @@
+pub mod error;
 pub mod evaluator;
 pub mod functions;
+pub mod plan;
+pub mod rules;
+pub mod rules_v2;
 pub mod rules_v3;
+pub mod trace;
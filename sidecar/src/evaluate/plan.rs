@@ -0,0 +1,127 @@
+use std::{collections::HashSet, sync::Arc};
+
+use prost_types::Any;
+
+use crate::gen::cli::lekko::feature::v1beta1::{Any as LekkoAny, Constraint, Feature};
+
+use super::error::EvalError;
+use super::rules::Rule;
+use super::{rules_v2, rules_v3};
+
+// CompiledFeature is a feature along with an evaluation plan for its tree, built
+// once when the feature is loaded. The compiled constraints mirror the feature's
+// constraint tree, and are walked alongside it during evaluation.
+pub struct CompiledFeature {
+    feature: Feature,
+    pub(crate) default: Option<Any>,
+    pub(crate) constraints: Vec<CompiledConstraint>,
+}
+
+pub(crate) struct CompiledConstraint {
+    pub(crate) rule: Rule,
+    pub(crate) value: Option<Any>,
+    pub(crate) constraints: Vec<CompiledConstraint>,
+}
+
+impl CompiledFeature {
+    pub fn new(namespace: &str, feature: Feature, interner: &mut Interner) -> Self {
+        let mut compiler = Compiler::new(namespace, &feature.key, interner);
+        let default = feature
+            .tree
+            .as_ref()
+            .and_then(|tree| resolve_value(&tree.default_new, &tree.default));
+        let constraints = feature
+            .tree
+            .as_ref()
+            .map(|tree| compile_constraints(&tree.constraints, &mut compiler))
+            .unwrap_or_default();
+        Self {
+            feature,
+            default,
+            constraints,
+        }
+    }
+
+    // The feature as it was loaded.
+    pub fn proto(&self) -> &Feature {
+        &self.feature
+    }
+}
+
+// rule_ast is deprecated, but is still the only rule on constraints written
+// before v1beta3.
+#[allow(deprecated)]
+fn compile_constraints(
+    constraints: &[Constraint],
+    compiler: &mut Compiler,
+) -> Vec<CompiledConstraint> {
+    constraints
+        .iter()
+        .map(|constraint| CompiledConstraint {
+            rule: match (&constraint.rule_ast_new, &constraint.rule_ast) {
+                (Some(ast), _) => rules_v3::compile_rule(ast, compiler),
+                (None, Some(ast)) => rules_v2::compile_rule(ast, compiler),
+                (None, None) => Rule::Invalid(EvalError::MalformedRule("empty rule")),
+            },
+            value: resolve_value(&constraint.value_new, &constraint.value),
+            constraints: compile_constraints(&constraint.constraints, compiler),
+        })
+        .collect()
+}
+
+// Values are written to the Lekko Any fields by newer toolchains, and to the
+// protobuf Any fields by older ones. The newer field is preferred if both are set.
+fn resolve_value(value_new: &Option<LekkoAny>, value: &Option<Any>) -> Option<Any> {
+    match value_new {
+        Some(LekkoAny { type_url, value }) => Some(Any {
+            type_url: type_url.clone(),
+            value: value.clone(),
+        }),
+        None => value.clone(),
+    }
+}
+
+// Interner deduplicates strings, such as context keys, that are repeated across
+// the features of a repository.
+#[derive(Default)]
+pub struct Interner(HashSet<Arc<str>>);
+
+impl Interner {
+    pub fn intern(&mut self, s: &str) -> Arc<str> {
+        if let Some(interned) = self.0.get(s) {
+            return interned.clone();
+        }
+        let interned: Arc<str> = Arc::from(s);
+        self.0.insert(interned.clone());
+        interned
+    }
+}
+
+// State needed while compiling the rules of a single feature.
+pub struct Compiler<'a> {
+    namespace: &'a str,
+    feature_name: &'a str,
+    interner: &'a mut Interner,
+}
+
+impl<'a> Compiler<'a> {
+    pub fn new(namespace: &'a str, feature_name: &'a str, interner: &'a mut Interner) -> Self {
+        Self {
+            namespace,
+            feature_name,
+            interner,
+        }
+    }
+
+    pub fn namespace(&self) -> &'a str {
+        self.namespace
+    }
+
+    pub fn feature_name(&self) -> &'a str {
+        self.feature_name
+    }
+
+    pub fn intern(&mut self, s: &str) -> Arc<str> {
+        self.interner.intern(s)
+    }
+}
@@ -0,0 +1,426 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use prost_types::value::Kind;
+use semver::Version;
+
+use crate::gen::cli::lekko::rules::v1beta3::{
+    ComparisonOperator as CmpOp,
+    LogicalOperator::{self, And, Or},
+};
+use crate::gen::sdk::lekko::client::v1beta1::{value::Kind as LekkoKind, Value as LekkoValue};
+use crate::types::TypeMismatchPolicy;
+
+use super::error::{kind_name, rule_kind_name, EvalError};
+use super::evaluator::EvalContext;
+use super::functions::{evaluate_to, Bucket, EvaluateTo};
+use super::trace::{atom_check, bucket_check, evaluate_to_check};
+
+// Rule is the compiled form of a feature's rules. Rules are compiled once when a
+// feature is loaded, so that rule values don't need to be re-parsed on every
+// evaluation. Malformed rules still compile, and only fail evaluation if they
+// are reached, the same as if they were evaluated from their proto form.
+pub enum Rule {
+    BoolConst(bool),
+    Not(Box<Rule>),
+    LogicalExpression(LogicalOperator, Vec<Rule>),
+    Atom(Atom),
+    Bucket(Bucket),
+    EvaluateTo(EvaluateTo),
+    // A rule that fails evaluation with the given error.
+    Invalid(EvalError),
+}
+
+pub struct Atom {
+    pub context_key: Arc<str>,
+    pub comparison_operator: CmpOp,
+    // The comparison value as written in the rule, kept for tracing.
+    pub comparison_value: Option<prost_types::Value>,
+    comparison: Comparison,
+}
+
+// The comparison an atom makes against the context value, with the comparison
+// value already parsed for the operator.
+enum Comparison {
+    Present,
+    Equals(Literal),
+    NotEquals(Literal),
+    Number(CmpOp, f64),
+    // Ordering comparison between semantic versions, for string context values.
+    Version(CmpOp, Version),
+    ContainedWithin(ValueSet),
+    String(CmpOp, String),
+    // The comparison can't be made. The error is returned if the context key is present.
+    Invalid(EvalError),
+}
+
+enum Literal {
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+impl Literal {
+    fn kind_name(&self) -> &'static str {
+        match self {
+            Literal::Bool(_) => "bool",
+            Literal::Number(_) => "number",
+            Literal::String(_) => "string",
+        }
+    }
+}
+
+// Set of rule values for constant time membership checks. Numbers are keyed by
+// their bit pattern, so that ints and doubles in the context compare the same way
+// as they do for equality.
+#[derive(Default)]
+struct ValueSet {
+    bools: [bool; 2],
+    numbers: HashSet<u64>,
+    strings: HashSet<String>,
+}
+
+impl ValueSet {
+    fn insert(&mut self, kind: &Kind) {
+        match kind {
+            Kind::BoolValue(b) => self.bools[*b as usize] = true,
+            // NaN is never equal to anything, so it isn't added to the set.
+            Kind::NumberValue(n) if !n.is_nan() => {
+                self.numbers.insert(number_key(*n));
+            }
+            Kind::StringValue(s) => {
+                self.strings.insert(s.clone());
+            }
+            // Other kinds can't be compared for equality, and never match.
+            _ => (),
+        }
+    }
+
+    fn contains(&self, ctx_kind: &LekkoKind) -> bool {
+        match ctx_kind {
+            LekkoKind::BoolValue(b) => self.bools[*b as usize],
+            LekkoKind::IntValue(i) => self.numbers.contains(&number_key(*i as f64)),
+            LekkoKind::DoubleValue(d) => self.numbers.contains(&number_key(*d)),
+            LekkoKind::StringValue(s) => self.strings.contains(s),
+        }
+    }
+}
+
+fn number_key(n: f64) -> u64 {
+    // 0.0 and -0.0 are equal, but have different bit patterns.
+    if n == 0.0 {
+        0.0f64.to_bits()
+    } else {
+        n.to_bits()
+    }
+}
+
+impl Rule {
+    // The error of the first part of the rule that can never be evaluated, if any.
+    // Such rules compile, but are rejected when a snapshot is validated.
+    //
+    // Only structural errors count. A rule value of the wrong type for its operator
+    // is a type mismatch, which the type mismatch policy can turn into a comparison
+    // that doesn't pass.
+    pub(crate) fn first_invalid(&self) -> Option<&EvalError> {
+        match self {
+            Rule::Not(rule) => rule.first_invalid(),
+            Rule::LogicalExpression(_, rules) => rules.iter().find_map(Rule::first_invalid),
+            Rule::Atom(Atom {
+                comparison: Comparison::Invalid(e),
+                ..
+            })
+            | Rule::Invalid(e) => match e {
+                EvalError::MalformedRule(_)
+                | EvalError::UnsupportedOperator(_)
+                | EvalError::UnknownFunction => Some(e),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Atom {
+    pub fn new(
+        context_key: Arc<str>,
+        comparison_operator: CmpOp,
+        comparison_value: Option<prost_types::Value>,
+    ) -> Self {
+        let comparison = match (comparison_operator, &comparison_value) {
+            (CmpOp::Present, _) => Comparison::Present,
+            (_, None) => Comparison::Invalid(EvalError::MalformedRule("empty comparison value")),
+            (_, Some(prost_types::Value { kind: None })) => {
+                Comparison::Invalid(EvalError::MalformedRule("empty rule value kind"))
+            }
+            (op, Some(prost_types::Value { kind: Some(kind) })) => {
+                compile_comparison(&context_key, op, kind)
+            }
+        };
+        Self {
+            context_key,
+            comparison_operator,
+            comparison_value,
+            comparison,
+        }
+    }
+}
+
+fn compile_comparison(key: &str, op: CmpOp, kind: &Kind) -> Comparison {
+    // The rule value can't be used with the operator.
+    let type_mismatch = |expected: &str| {
+        Comparison::Invalid(EvalError::type_mismatch(
+            key,
+            expected,
+            format!("{} rule value", rule_kind_name(kind)),
+        ))
+    };
+    match op {
+        CmpOp::Equals | CmpOp::NotEquals => {
+            let literal = match kind {
+                Kind::BoolValue(b) => Literal::Bool(*b),
+                Kind::NumberValue(n) => Literal::Number(*n),
+                Kind::StringValue(s) => Literal::String(s.clone()),
+                _ => {
+                    return Comparison::Invalid(EvalError::MalformedRule(
+                        "unsupported rule value kind",
+                    ))
+                }
+            };
+            if op == CmpOp::Equals {
+                Comparison::Equals(literal)
+            } else {
+                Comparison::NotEquals(literal)
+            }
+        }
+        CmpOp::LessThan
+        | CmpOp::LessThanOrEquals
+        | CmpOp::GreaterThan
+        | CmpOp::GreaterThanOrEquals => match kind {
+            Kind::NumberValue(n) => Comparison::Number(op, *n),
+            Kind::StringValue(s) => match Version::parse(s) {
+                Ok(version) => Comparison::Version(op, version),
+                Err(_) => Comparison::Invalid(not_a_version(key, s)),
+            },
+            _ => type_mismatch("number or semantic version"),
+        },
+        CmpOp::ContainedWithin => match kind {
+            Kind::ListValue(list) => {
+                let mut set = ValueSet::default();
+                for value in &list.values {
+                    match &value.kind {
+                        Some(kind) => set.insert(kind),
+                        None => {
+                            return Comparison::Invalid(EvalError::MalformedRule(
+                                "empty rule value kind",
+                            ))
+                        }
+                    }
+                }
+                Comparison::ContainedWithin(set)
+            }
+            _ => type_mismatch("list"),
+        },
+        CmpOp::StartsWith | CmpOp::EndsWith | CmpOp::Contains => match kind {
+            Kind::StringValue(s) => Comparison::String(op, s.clone()),
+            _ => type_mismatch("string"),
+        },
+        CmpOp::Present => Comparison::Present,
+        CmpOp::Unspecified => Comparison::Invalid(EvalError::UnsupportedOperator(op.as_str_name())),
+    }
+}
+
+// check_rule evaluates the rule using the given context to determine whether or not the rule passed.
+// it is a recursive method.
+pub fn check_rule(
+    rule: &Rule,
+    context: &HashMap<String, LekkoValue>,
+    eval_context: &EvalContext,
+) -> Result<bool, EvalError> {
+    match rule {
+        // Base case
+        Rule::BoolConst(b) => Ok(*b),
+        // Recursive case
+        Rule::Not(not_rule) => Ok(!check_rule(not_rule.as_ref(), context, eval_context)?),
+        // Recursive case
+        Rule::LogicalExpression(operator, rules) => {
+            check_rules(rules, operator, context, eval_context)
+        }
+        // Base case
+        Rule::Atom(a) => {
+            let result = match check_atom(a, context) {
+                Err(EvalError::TypeMismatch { .. })
+                    if eval_context.type_mismatch_policy == TypeMismatchPolicy::False =>
+                {
+                    Ok(false)
+                }
+                result => result,
+            };
+            eval_context.trace_check(|| atom_check(a, context, &result));
+            result
+        }
+        Rule::Bucket(bucket_f) => {
+            let result = bucket_f.check(context);
+            eval_context.trace_check(|| bucket_check(bucket_f, context, &result));
+            result
+        }
+        Rule::EvaluateTo(evaluate_to_f) => {
+            let result = evaluate_to(evaluate_to_f, context, eval_context);
+            eval_context.trace_check(|| evaluate_to_check(evaluate_to_f, &result));
+            result
+        }
+        Rule::Invalid(e) => Err(e.clone()),
+    }
+}
+
+fn check_atom(a: &Atom, context: &HashMap<String, LekkoValue>) -> Result<bool, EvalError> {
+    let ctx_value = context.get(&*a.context_key);
+    if let Comparison::Present = a.comparison {
+        return Ok(ctx_value.is_some());
+    }
+    let Some(ctx_value) = ctx_value else {
+        // All other comparison operators expect the context key to be present. If
+        // it is not present, return false.
+        return Ok(false);
+    };
+    if let Comparison::Invalid(e) = &a.comparison {
+        return Err(e.clone());
+    }
+    let key = &*a.context_key;
+    let ctx_kind = ctx_value
+        .kind
+        .as_ref()
+        .ok_or_else(|| EvalError::EmptyContextValue {
+            key: key.to_owned(),
+        })?;
+    match &a.comparison {
+        Comparison::Equals(literal) => check_equals_cmp(key, literal, ctx_kind),
+        Comparison::NotEquals(literal) => Ok(!check_equals_cmp(key, literal, ctx_kind)?),
+        Comparison::Number(op, rule_num) => check_num_cmp(key, op, *rule_num, ctx_kind),
+        Comparison::Version(op, rule_version) => check_version_cmp(key, op, rule_version, ctx_kind),
+        Comparison::ContainedWithin(set) => Ok(set.contains(ctx_kind)),
+        Comparison::String(op, rule_str) => check_str_cmp(key, op, rule_str, ctx_kind),
+        Comparison::Present | Comparison::Invalid(_) => Err(EvalError::UnsupportedOperator(
+            a.comparison_operator.as_str_name(),
+        )),
+    }
+}
+
+// check_rules evaluates the rules in order, stopping at the first rule that fails for And,
+// or the first rule that passes for Or. Rules after that point are not evaluated, so
+// they cannot cause the expression to error.
+pub fn check_rules(
+    rules: &[Rule],
+    operator: &LogicalOperator,
+    context: &HashMap<String, LekkoValue>,
+    eval_context: &EvalContext,
+) -> Result<bool, EvalError> {
+    if rules.is_empty() {
+        return Err(EvalError::MalformedRule(
+            "no rules found in logical expression",
+        ));
+    }
+    // The result that ends evaluation early.
+    let short_circuit = match operator {
+        And => false,
+        Or => true,
+        LogicalOperator::Unspecified => {
+            return Err(EvalError::UnsupportedOperator(operator.as_str_name()))
+        }
+    };
+    for rule in rules {
+        if check_rule(rule, context, eval_context)? == short_circuit {
+            return Ok(short_circuit);
+        }
+    }
+    Ok(!short_circuit)
+}
+
+fn check_equals_cmp(key: &str, literal: &Literal, ctx_kind: &LekkoKind) -> Result<bool, EvalError> {
+    match (literal, ctx_kind) {
+        (Literal::Bool(rule_bool), LekkoKind::BoolValue(ctx_bool)) => Ok(rule_bool == ctx_bool),
+        (Literal::Number(rule_num), LekkoKind::IntValue(ctx_num)) => {
+            Ok(*rule_num == *ctx_num as f64)
+        }
+        (Literal::Number(rule_num), LekkoKind::DoubleValue(ctx_num)) => Ok(rule_num == ctx_num),
+        (Literal::String(rule_str), LekkoKind::StringValue(ctx_str)) => Ok(rule_str == ctx_str),
+        _ => Err(EvalError::type_mismatch(
+            key,
+            literal.kind_name(),
+            kind_name(ctx_kind),
+        )),
+    }
+}
+
+fn check_num_cmp(
+    key: &str,
+    co: &CmpOp,
+    rule_num: f64,
+    ctx_kind: &LekkoKind,
+) -> Result<bool, EvalError> {
+    let ctx_num = get_lekko_number(key, ctx_kind)?;
+    match co {
+        CmpOp::LessThan => Ok(ctx_num < rule_num),
+        CmpOp::LessThanOrEquals => Ok(ctx_num <= rule_num),
+        CmpOp::GreaterThan => Ok(ctx_num > rule_num),
+        CmpOp::GreaterThanOrEquals => Ok(ctx_num >= rule_num),
+        _ => Err(EvalError::UnsupportedOperator(co.as_str_name())),
+    }
+}
+
+// Versions are compared by semantic versioning 2.0.0 precedence, so pre-releases
+// order before their release, and build metadata is ignored.
+fn check_version_cmp(
+    key: &str,
+    co: &CmpOp,
+    rule_version: &Version,
+    ctx_kind: &LekkoKind,
+) -> Result<bool, EvalError> {
+    let ctx_str = get_lekko_string(key, ctx_kind)?;
+    let ctx_version = Version::parse(ctx_str).map_err(|_| not_a_version(key, ctx_str))?;
+    let ordering = ctx_version.cmp_precedence(rule_version);
+    match co {
+        CmpOp::LessThan => Ok(ordering == Ordering::Less),
+        CmpOp::LessThanOrEquals => Ok(ordering != Ordering::Greater),
+        CmpOp::GreaterThan => Ok(ordering == Ordering::Greater),
+        CmpOp::GreaterThanOrEquals => Ok(ordering != Ordering::Less),
+        _ => Err(EvalError::UnsupportedOperator(co.as_str_name())),
+    }
+}
+
+fn not_a_version(key: &str, s: &str) -> EvalError {
+    EvalError::type_mismatch(key, "semantic version", format!("{s:?}"))
+}
+
+fn check_str_cmp(
+    key: &str,
+    co: &CmpOp,
+    rule_str: &str,
+    ctx_kind: &LekkoKind,
+) -> Result<bool, EvalError> {
+    let ctx_str = get_lekko_string(key, ctx_kind)?;
+    match co {
+        CmpOp::StartsWith => Ok(ctx_str.starts_with(rule_str)),
+        CmpOp::EndsWith => Ok(ctx_str.ends_with(rule_str)),
+        CmpOp::Contains => Ok(ctx_str.contains(rule_str)),
+        _ => Err(EvalError::UnsupportedOperator(co.as_str_name())),
+    }
+}
+
+fn get_lekko_number(key: &str, kind: &LekkoKind) -> Result<f64, EvalError> {
+    match kind {
+        LekkoKind::IntValue(int_value) => Ok(*int_value as f64),
+        LekkoKind::DoubleValue(double_value) => Ok(*double_value),
+        _ => Err(EvalError::type_mismatch(key, "number", kind_name(kind))),
+    }
+}
+
+fn get_lekko_string<'a>(key: &str, kind: &'a LekkoKind) -> Result<&'a str, EvalError> {
+    match kind {
+        LekkoKind::StringValue(str_value) => Ok(str_value),
+        _ => Err(EvalError::type_mismatch(key, "string", kind_name(kind))),
+    }
+}
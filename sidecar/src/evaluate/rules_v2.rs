@@ -0,0 +1,318 @@
+use crate::gen::cli::lekko::rules::{
+    v1beta2::{
+        rule::Rule::{Atom, BoolConst, LogicalExpression, Not},
+        ComparisonOperator, LogicalOperator, Rule,
+    },
+    v1beta3,
+};
+
+use super::error::EvalError;
+use super::plan::Compiler;
+use super::rules::{self, Rule as CompiledRule};
+
+// compile_rule compiles a v1beta2 rule for evaluation. It is only used for
+// constraints that were written before v1beta3, and have no rule_ast_new.
+// v1beta2 rules are a subset of v1beta3 rules, and compile to the same plan.
+pub fn compile_rule(rule: &Rule, compiler: &mut Compiler) -> CompiledRule {
+    let Some(r) = rule.rule.as_ref() else {
+        return CompiledRule::Invalid(EvalError::MalformedRule("empty rule"));
+    };
+    match r {
+        BoolConst(b) => CompiledRule::BoolConst(*b),
+        Not(not_rule) => CompiledRule::Not(Box::new(compile_rule(not_rule, compiler))),
+        LogicalExpression(le) => {
+            let (Some(first), Some(second)) = (le.first_rule.as_ref(), le.second_rule.as_ref())
+            else {
+                return CompiledRule::Invalid(EvalError::MalformedRule("empty logical expression"));
+            };
+            CompiledRule::LogicalExpression(
+                logical_operator(le.logical_operator()),
+                vec![
+                    compile_rule(first, compiler),
+                    compile_rule(second, compiler),
+                ],
+            )
+        }
+        Atom(a) => CompiledRule::Atom(rules::Atom::new(
+            compiler.intern(&a.context_key),
+            comparison_operator(a.comparison_operator()),
+            a.comparison_value.clone(),
+        )),
+    }
+}
+
+fn logical_operator(op: LogicalOperator) -> v1beta3::LogicalOperator {
+    match op {
+        LogicalOperator::Unspecified => v1beta3::LogicalOperator::Unspecified,
+        LogicalOperator::And => v1beta3::LogicalOperator::And,
+        LogicalOperator::Or => v1beta3::LogicalOperator::Or,
+    }
+}
+
+fn comparison_operator(op: ComparisonOperator) -> v1beta3::ComparisonOperator {
+    match op {
+        ComparisonOperator::Unspecified => v1beta3::ComparisonOperator::Unspecified,
+        ComparisonOperator::Equals => v1beta3::ComparisonOperator::Equals,
+        ComparisonOperator::LessThan => v1beta3::ComparisonOperator::LessThan,
+        ComparisonOperator::LessThanOrEquals => v1beta3::ComparisonOperator::LessThanOrEquals,
+        ComparisonOperator::GreaterThan => v1beta3::ComparisonOperator::GreaterThan,
+        ComparisonOperator::GreaterThanOrEquals => v1beta3::ComparisonOperator::GreaterThanOrEquals,
+        ComparisonOperator::ContainedWithin => v1beta3::ComparisonOperator::ContainedWithin,
+        ComparisonOperator::StartsWith => v1beta3::ComparisonOperator::StartsWith,
+        ComparisonOperator::EndsWith => v1beta3::ComparisonOperator::EndsWith,
+        ComparisonOperator::Contains => v1beta3::ComparisonOperator::Contains,
+        ComparisonOperator::Present => v1beta3::ComparisonOperator::Present,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluate::evaluator::{evaluate, EvalContext};
+    use crate::evaluate::plan::{CompiledFeature, Interner};
+    use crate::evaluate::rules::check_rule;
+    use crate::evaluate::rules_v3;
+    use crate::gen::cli::lekko::feature::v1beta1::{Constraint, Feature, Tree};
+    use crate::gen::cli::lekko::rules::v1beta2::{
+        rule::Rule as RuleKind, LogicalExpression as V2Logical,
+    };
+    use crate::gen::cli::lekko::rules::v1beta3::{
+        rule::Rule as V3RuleKind, LogicalExpression as V3Logical,
+    };
+    use crate::gen::sdk::lekko::client::v1beta1::{value::Kind as LekkoKind, Value as LekkoValue};
+    use crate::types::to_any;
+    use prost_types::value::Kind;
+    use std::collections::HashMap;
+
+    // The same rule, encoded as both v1beta2 and v1beta3.
+    struct Both {
+        v2: Rule,
+        v3: v1beta3::Rule,
+    }
+
+    fn atom(key: &str, op: ComparisonOperator, value: Option<Kind>) -> Both {
+        let comparison_value = value.map(|kind| prost_types::Value { kind: Some(kind) });
+        Both {
+            v2: Rule {
+                rule: Some(RuleKind::Atom(
+                    crate::gen::cli::lekko::rules::v1beta2::Atom {
+                        context_key: key.to_owned(),
+                        comparison_value: comparison_value.clone(),
+                        comparison_operator: op.into(),
+                    },
+                )),
+            },
+            v3: v1beta3::Rule {
+                rule: Some(V3RuleKind::Atom(v1beta3::Atom {
+                    context_key: key.to_owned(),
+                    comparison_value,
+                    comparison_operator: comparison_operator(op).into(),
+                })),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn logical(op: LogicalOperator, first: Both, second: Both) -> Both {
+        Both {
+            v2: Rule {
+                rule: Some(RuleKind::LogicalExpression(Box::new(V2Logical {
+                    first_rule: Some(Box::new(first.v2)),
+                    second_rule: Some(Box::new(second.v2)),
+                    logical_operator: op.into(),
+                }))),
+            },
+            v3: v1beta3::Rule {
+                rule: Some(V3RuleKind::LogicalExpression(V3Logical {
+                    rules: vec![first.v3, second.v3],
+                    logical_operator: logical_operator(op).into(),
+                })),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn not(rule: Both) -> Both {
+        Both {
+            v2: Rule {
+                rule: Some(RuleKind::Not(Box::new(rule.v2))),
+            },
+            v3: v1beta3::Rule {
+                rule: Some(V3RuleKind::Not(Box::new(rule.v3))),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn string(s: &str) -> Kind {
+        Kind::StringValue(s.to_owned())
+    }
+
+    fn list(values: Vec<Kind>) -> Kind {
+        Kind::ListValue(prost_types::ListValue {
+            values: values
+                .into_iter()
+                .map(|kind| prost_types::Value { kind: Some(kind) })
+                .collect(),
+        })
+    }
+
+    fn contexts() -> Vec<HashMap<String, LekkoValue>> {
+        let value = |kind: LekkoKind| LekkoValue { kind: Some(kind) };
+        vec![
+            HashMap::new(),
+            HashMap::from([
+                (
+                    String::from("country"),
+                    value(LekkoKind::StringValue(String::from("US"))),
+                ),
+                (String::from("age"), value(LekkoKind::IntValue(30))),
+                (String::from("beta"), value(LekkoKind::BoolValue(true))),
+            ]),
+            HashMap::from([
+                (
+                    String::from("country"),
+                    value(LekkoKind::StringValue(String::from("CA"))),
+                ),
+                (String::from("age"), value(LekkoKind::DoubleValue(17.5))),
+                (String::from("beta"), value(LekkoKind::BoolValue(false))),
+            ]),
+            // Type mismatches must fail the same way in both encodings.
+            HashMap::from([
+                (String::from("country"), value(LekkoKind::IntValue(1))),
+                (
+                    String::from("age"),
+                    value(LekkoKind::StringValue(String::from("old"))),
+                ),
+            ]),
+        ]
+    }
+
+    fn rules() -> Vec<Both> {
+        use ComparisonOperator::*;
+        vec![
+            atom("country", Equals, Some(string("US"))),
+            atom("age", LessThan, Some(Kind::NumberValue(18.0))),
+            atom("age", LessThanOrEquals, Some(Kind::NumberValue(30.0))),
+            atom("age", GreaterThan, Some(Kind::NumberValue(18.0))),
+            atom("age", GreaterThanOrEquals, Some(Kind::NumberValue(30.0))),
+            atom(
+                "country",
+                ContainedWithin,
+                Some(list(vec![string("US"), string("MX")])),
+            ),
+            atom("country", StartsWith, Some(string("U"))),
+            atom("country", EndsWith, Some(string("A"))),
+            atom("country", Contains, Some(string("S"))),
+            atom("beta", Present, None),
+            atom("beta", Equals, None),
+            atom("beta", Unspecified, Some(Kind::BoolValue(true))),
+            not(atom("beta", Equals, Some(Kind::BoolValue(true)))),
+            logical(
+                LogicalOperator::And,
+                atom("country", Equals, Some(string("US"))),
+                atom("age", GreaterThan, Some(Kind::NumberValue(21.0))),
+            ),
+            logical(
+                LogicalOperator::Or,
+                atom("beta", Equals, Some(Kind::BoolValue(false))),
+                not(atom("age", Present, None)),
+            ),
+            logical(
+                LogicalOperator::Unspecified,
+                atom("beta", Present, None),
+                atom("age", Present, None),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_parity() {
+        let mut interner = Interner::default();
+        let mut compiler = Compiler::new("ns", "feature", &mut interner);
+        for rule in rules() {
+            let v2 = compile_rule(&rule.v2, &mut compiler);
+            let v3 = rules_v3::compile_rule(&rule.v3, &mut compiler);
+            for context in contexts() {
+                let eval_context = EvalContext::default();
+                assert_eq!(
+                    check_rule(&v2, &context, &eval_context),
+                    check_rule(&v3, &context, &eval_context),
+                    "{:?} {context:?}",
+                    rule.v2
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_logical_expression() {
+        let rule = Rule {
+            rule: Some(RuleKind::LogicalExpression(Box::new(V2Logical {
+                first_rule: Some(Box::new(Rule {
+                    rule: Some(RuleKind::BoolConst(true)),
+                })),
+                second_rule: None,
+                logical_operator: LogicalOperator::And.into(),
+            }))),
+        };
+        let mut interner = Interner::default();
+        let compiled = compile_rule(&rule, &mut Compiler::new("ns", "feature", &mut interner));
+        let err = check_rule(&compiled, &HashMap::new(), &EvalContext::default()).unwrap_err();
+        assert_eq!(err, EvalError::MalformedRule("empty logical expression"));
+    }
+
+    // Trees may mix encodings, e.g. when a constraint was added to a feature
+    // after v1beta3 was introduced.
+    #[test]
+    #[allow(deprecated)]
+    fn test_legacy_constraints() {
+        let constraint = |rule: Both, value: i64, legacy: bool| Constraint {
+            value: Some(to_any(&value)),
+            rule_ast: legacy.then_some(rule.v2),
+            rule_ast_new: (!legacy).then_some(rule.v3),
+            ..Default::default()
+        };
+        let feature = Feature {
+            key: String::from("feature"),
+            tree: Some(Tree {
+                default: Some(to_any(&0_i64)),
+                constraints: vec![
+                    constraint(
+                        atom("country", ComparisonOperator::Equals, Some(string("US"))),
+                        1,
+                        true,
+                    ),
+                    constraint(
+                        atom("country", ComparisonOperator::Equals, Some(string("CA"))),
+                        2,
+                        false,
+                    ),
+                    // A constraint with neither rule can't be evaluated.
+                    Constraint {
+                        value: Some(to_any(&3_i64)),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let compiled = CompiledFeature::new("ns", feature, &mut Interner::default());
+        let eval = |country: &str| {
+            let context = HashMap::from([(
+                String::from("country"),
+                LekkoValue {
+                    kind: Some(LekkoKind::StringValue(country.to_owned())),
+                },
+            )]);
+            evaluate(&compiled, &context, &EvalContext::default())
+        };
+        assert_eq!(eval("US").unwrap(), (to_any(&1_i64), vec![0]));
+        assert_eq!(eval("CA").unwrap(), (to_any(&2_i64), vec![1]));
+        assert_eq!(
+            eval("MX").unwrap_err(),
+            EvalError::MalformedRule("empty rule")
+        );
+    }
+}
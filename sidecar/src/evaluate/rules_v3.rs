@@ -1,222 +1,347 @@
-use std::collections::HashMap;
-
-use prost_types::{
-    value::Kind,
-    value::Kind::{BoolValue, NumberValue, StringValue},
-};
-use tonic::Status;
-
-use crate::gen::sdk::lekko::client::{
-    self, v1beta1::value::Kind as LekkoKind, v1beta1::Value as LekkoValue,
-};
-
 use crate::gen::cli::lekko::rules::v1beta3::{
     call_expression::Function,
     rule::Rule::{Atom, BoolConst, CallExpression, LogicalExpression, Not},
-    ComparisonOperator as CmpOp,
-    LogicalOperator::{self, And, Or},
     Rule,
 };
 
-use super::evaluator::EvalContext;
-use super::functions::bucket;
+use super::error::EvalError;
+use super::functions::{Bucket, EvaluateTo};
+use super::plan::Compiler;
+use super::rules::{self, Rule as CompiledRule};
 
-// TODO: make all error messages contain dynamic variable info.
-// check_rule evaluates the rule using the given context to determine whether or not the rule passed.
-// it is a recursive method.
-pub fn check_rule(
-    rule: &Rule,
-    context: &HashMap<String, LekkoValue>,
-    eval_context: &EvalContext,
-) -> Result<bool, Status> {
-    let r = rule
-        .rule
-        .as_ref()
-        .ok_or_else(|| Status::internal("empty rule"))?;
+// compile_rule compiles a v1beta3 rule for evaluation. Rules that can't be
+// evaluated compile to an invalid rule, which only fails evaluation if reached.
+pub fn compile_rule(rule: &Rule, compiler: &mut Compiler) -> CompiledRule {
+    let Some(r) = rule.rule.as_ref() else {
+        return CompiledRule::Invalid(EvalError::MalformedRule("empty rule"));
+    };
     match r {
-        // Base case
-        BoolConst(b) => Ok(*b),
-        // Recursive case
-        Not(not_rule) => Ok(!check_rule(not_rule.as_ref(), context, eval_context)?),
-        // Recursive case
-        LogicalExpression(le) => Ok(check_rules(
-            le.rules.as_ref(),
-            &le.logical_operator(),
-            context,
-            eval_context,
-        )?),
-        // Base case
-        Atom(a) => {
-            let ctx_key = &a.context_key;
-            let present = context.contains_key(ctx_key);
-            if a.comparison_operator().eq(&CmpOp::Present) {
-                return Ok(present);
-            }
-            if a.comparison_value.is_none() {
-                return Err(Status::internal("empty comparison value"));
-            }
-            if !present {
-                // All other comparison operators expect the context key to be present. If
-                // it is not present, return false.
-                return Ok(false);
-            }
-            let rule_kind = a
-                .comparison_value
-                .as_ref()
-                .unwrap()
-                .kind
-                .as_ref()
-                .ok_or_else(|| Status::internal("empty rule value kind"))?;
-            let ctx_kind = context
-                .get(ctx_key)
-                .ok_or_else(|| Status::internal("empty ctx value"))?
-                .kind
-                .as_ref()
-                .ok_or_else(|| Status::internal("empty ctx value kind"))?;
-            match a.comparison_operator() {
-                CmpOp::Equals => check_equals_cmp(rule_kind, ctx_kind),
-                CmpOp::NotEquals => match check_equals_cmp(rule_kind, ctx_kind) {
-                    Ok(b) => Ok(!b),
-                    Err(e) => Err(e),
-                },
-                CmpOp::LessThan => check_num_cmp(&a.comparison_operator(), rule_kind, ctx_kind),
-                CmpOp::LessThanOrEquals => {
-                    check_num_cmp(&a.comparison_operator(), rule_kind, ctx_kind)
-                }
-                CmpOp::GreaterThan => check_num_cmp(&a.comparison_operator(), rule_kind, ctx_kind),
-                CmpOp::GreaterThanOrEquals => {
-                    check_num_cmp(&a.comparison_operator(), rule_kind, ctx_kind)
-                }
-                CmpOp::ContainedWithin => check_list_cmp(rule_kind, ctx_kind),
-                CmpOp::StartsWith => check_str_cmp(&a.comparison_operator(), rule_kind, ctx_kind),
-                CmpOp::EndsWith => check_str_cmp(&a.comparison_operator(), rule_kind, ctx_kind),
-                CmpOp::Contains => check_str_cmp(&a.comparison_operator(), rule_kind, ctx_kind),
-                CmpOp::Present => Err(Status::internal("present should be handled above")),
-                CmpOp::Unspecified => Err(Status::internal("unknown comparison operator")),
-            }
+        BoolConst(b) => CompiledRule::BoolConst(*b),
+        Not(not_rule) => CompiledRule::Not(Box::new(compile_rule(not_rule, compiler))),
+        LogicalExpression(le) => CompiledRule::LogicalExpression(
+            le.logical_operator(),
+            le.rules
+                .iter()
+                .map(|rule| compile_rule(rule, compiler))
+                .collect(),
+        ),
+        Atom(a) => CompiledRule::Atom(rules::Atom::new(
+            compiler.intern(&a.context_key),
+            a.comparison_operator(),
+            a.comparison_value.clone(),
+        )),
+        CallExpression(ce) => match ce.function.as_ref() {
+            None => CompiledRule::Invalid(EvalError::UnknownFunction),
+            Some(Function::Bucket(bucket_f)) => CompiledRule::Bucket(Bucket::new(
+                compiler.namespace(),
+                compiler.feature_name(),
+                compiler.intern(&bucket_f.context_key),
+                bucket_f.threshold,
+            )),
+            Some(Function::EvaluateTo(evaluate_to_f)) => CompiledRule::EvaluateTo(EvaluateTo {
+                config_name: evaluate_to_f.config_name.clone(),
+                config_value: evaluate_to_f.config_value.clone(),
+            }),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluate::evaluator::EvalContext;
+    use crate::evaluate::plan::Interner;
+    use crate::evaluate::rules::check_rule;
+    use crate::gen::cli::lekko::rules::v1beta3::{
+        rule::Rule as RuleKind, LogicalExpression as LogicalExpressionRule,
+    };
+    use crate::gen::cli::lekko::rules::v1beta3::{
+        rule::Rule::Atom as AtomRule,
+        Atom, ComparisonOperator as CmpOp,
+        LogicalOperator::{self, And, Or},
+    };
+    use crate::gen::sdk::lekko::client::v1beta1::{value::Kind as LekkoKind, Value as LekkoValue};
+    use crate::types::TypeMismatchPolicy;
+    use prost_types::value::Kind::NumberValue;
+    use std::collections::HashMap;
+
+    fn bool_rule(b: bool) -> Rule {
+        Rule {
+            rule: Some(RuleKind::BoolConst(b)),
+            ..Default::default()
         }
-        CallExpression(ce) => {
-            let function = ce
-                .function
-                .as_ref()
-                .ok_or_else(|| Status::internal("empty function"))?;
-            match function {
-                Function::Bucket(bucket_f) => bucket(bucket_f, context, eval_context),
-                Function::EvaluateTo(_) => Err(Status::internal(
-                    "evaluate_to is not currently supported in the sidecar",
-                )),
-            }
+    }
+
+    // A rule comparing the "age" context key, which is a string in the test context,
+    // against a number.
+    fn mismatch_rule() -> Rule {
+        Rule {
+            rule: Some(AtomRule(Atom {
+                context_key: String::from("age"),
+                comparison_value: Some(prost_types::Value {
+                    kind: Some(NumberValue(10.0)),
+                }),
+                comparison_operator: CmpOp::GreaterThan.into(),
+            })),
+            ..Default::default()
         }
     }
-}
 
-pub fn check_rules(
-    rules: &[Rule],
-    operator: &LogicalOperator,
-    context: &HashMap<String, LekkoValue>,
-    eval_context: &EvalContext,
-) -> Result<bool, Status> {
-    if rules.is_empty() {
-        return Err(Status::internal("no rules found in logical expression"));
+    fn logical_rule(operator: LogicalOperator, rules: Vec<Rule>) -> Rule {
+        Rule {
+            rule: Some(LogicalExpression(LogicalExpressionRule {
+                rules,
+                logical_operator: operator.into(),
+            })),
+            ..Default::default()
+        }
     }
-    let result: Result<Vec<bool>, Status> = rules
-        .iter()
-        .map(|rule| check_rule(rule, context, eval_context))
-        .collect();
-    return match (result, operator) {
-        (_, LogicalOperator::Unspecified) => Err(Status::internal("unknown logical operator")),
-        (Err(e), _) => Err(e),
-        (Ok(bools), And) => Ok(bools.iter().all(|b| b.to_owned())),
-        (Ok(bools), Or) => Ok(bools.iter().any(|b| b.to_owned())),
-    };
-}
 
-fn check_equals_cmp(rule_kind: &Kind, ctx_kind: &LekkoKind) -> Result<bool, Status> {
-    match rule_kind {
-        BoolValue(rule_bool) => match ctx_kind {
-            client::v1beta1::value::Kind::BoolValue(ctx_bool) => Ok(rule_bool == ctx_bool),
-            _ => Err(Status::invalid_argument("type mismatch")),
-        },
-        NumberValue(rule_num) => match ctx_kind {
-            client::v1beta1::value::Kind::IntValue(ctx_num) => Ok(*rule_num == *ctx_num as f64),
-            client::v1beta1::value::Kind::DoubleValue(ctx_num) => Ok(rule_num == ctx_num),
-            _ => Err(Status::invalid_argument("type mismatch")),
-        },
-        StringValue(rule_str) => match ctx_kind {
-            client::v1beta1::value::Kind::StringValue(ctx_str) => Ok(rule_str == ctx_str),
-            _ => Err(Status::invalid_argument("type mismatch")),
-        },
-        _ => Err(Status::internal("unsupported rule value kind")),
+    fn not_rule(rule: Rule) -> Rule {
+        Rule {
+            rule: Some(Not(Box::new(rule))),
+            ..Default::default()
+        }
     }
-}
 
-fn check_num_cmp(co: &CmpOp, rule_kind: &Kind, ctx_kind: &LekkoKind) -> Result<bool, Status> {
-    let rule_num = get_number(rule_kind)?;
-    let ctx_num = get_lekko_number(ctx_kind)?;
-    match co {
-        CmpOp::LessThan => Ok(ctx_num < rule_num),
-        CmpOp::LessThanOrEquals => Ok(ctx_num <= rule_num),
-        CmpOp::GreaterThan => Ok(ctx_num > rule_num),
-        CmpOp::GreaterThanOrEquals => Ok(ctx_num >= rule_num),
-        _ => Err(Status::internal("invalid comparison operator")),
+    fn check(rule: &Rule, policy: TypeMismatchPolicy) -> Result<bool, EvalError> {
+        let context = HashMap::from([(
+            String::from("age"),
+            LekkoValue {
+                kind: Some(LekkoKind::StringValue(String::from("ten"))),
+            },
+        )]);
+        let eval_context = EvalContext {
+            type_mismatch_policy: policy,
+            ..Default::default()
+        };
+        let mut interner = Interner::default();
+        let compiled = compile_rule(rule, &mut Compiler::new("ns", "feature", &mut interner));
+        check_rule(&compiled, &context, &eval_context)
     }
-}
 
-fn check_list_cmp(rule_kind: &Kind, ctx_kind: &LekkoKind) -> Result<bool, Status> {
-    match rule_kind {
-        Kind::ListValue(rule_list) => {
-            for rule_elem in &rule_list.values {
-                let rule_elem_kind = rule_elem
-                    .kind
-                    .as_ref()
-                    .ok_or_else(|| Status::internal("empty rule value kind"))?;
-                let elem_equal = check_equals_cmp(rule_elem_kind, ctx_kind);
-                if elem_equal.is_ok() && elem_equal.unwrap() {
-                    return Ok(true);
-                }
+    #[test]
+    fn test_short_circuit() {
+        let policy = TypeMismatchPolicy::Error;
+        // Rules after the deciding rule are not evaluated.
+        let rule = logical_rule(And, vec![bool_rule(false), mismatch_rule()]);
+        assert!(!check(&rule, policy).unwrap());
+        let rule = logical_rule(Or, vec![bool_rule(true), mismatch_rule()]);
+        assert!(check(&rule, policy).unwrap());
+        let rule = logical_rule(Or, vec![bool_rule(false), bool_rule(true), mismatch_rule()]);
+        assert!(check(&rule, policy).unwrap());
+        // Rules before the deciding rule are evaluated in declared order.
+        let rule = logical_rule(
+            And,
+            vec![bool_rule(true), mismatch_rule(), bool_rule(false)],
+        );
+        assert!(matches!(
+            check(&rule, policy),
+            Err(EvalError::TypeMismatch { .. })
+        ));
+        let rule = logical_rule(Or, vec![mismatch_rule(), bool_rule(true)]);
+        assert!(matches!(
+            check(&rule, policy),
+            Err(EvalError::TypeMismatch { .. })
+        ));
+        // Without a deciding rule, the result is the remaining value.
+        let rule = logical_rule(And, vec![bool_rule(true), bool_rule(true)]);
+        assert!(check(&rule, policy).unwrap());
+        let rule = logical_rule(Or, vec![bool_rule(false), bool_rule(false)]);
+        assert!(!check(&rule, policy).unwrap());
+    }
+
+    #[test]
+    fn test_type_mismatch_policy_error() {
+        let policy = TypeMismatchPolicy::Error;
+        let err = check(&mismatch_rule(), policy).unwrap_err();
+        assert_eq!(
+            err,
+            EvalError::TypeMismatch {
+                key: String::from("age"),
+                expected: String::from("number"),
+                actual: String::from("string"),
             }
-            Ok(false)
-        }
-        _ => Err(Status::invalid_argument("type mismatch")),
+        );
+        // type mismatches are the client's fault
+        assert_eq!(
+            crate::metrics::evaluation_error(err).code(),
+            tonic::Code::InvalidArgument
+        );
+        assert!(check(&not_rule(mismatch_rule()), policy).is_err());
     }
-}
 
-fn check_str_cmp(co: &CmpOp, rule_kind: &Kind, ctx_kind: &LekkoKind) -> Result<bool, Status> {
-    let rule_str = get_string(rule_kind)?;
-    let ctx_str = get_lekko_string(ctx_kind)?;
-    match co {
-        CmpOp::StartsWith => Ok(ctx_str.starts_with(&rule_str)),
-        CmpOp::EndsWith => Ok(ctx_str.ends_with(&rule_str)),
-        CmpOp::Contains => Ok(ctx_str.contains(&rule_str)),
-        _ => Err(Status::internal("invalid comparison operator")),
+    #[test]
+    fn test_type_mismatch_policy_false() {
+        let policy = TypeMismatchPolicy::False;
+        assert!(!check(&mismatch_rule(), policy).unwrap());
+        // The mismatched atom is false, so negating it passes.
+        assert!(check(&not_rule(mismatch_rule()), policy).unwrap());
+        let rule = logical_rule(Or, vec![mismatch_rule(), bool_rule(true)]);
+        assert!(check(&rule, policy).unwrap());
+        let rule = logical_rule(And, vec![bool_rule(true), mismatch_rule()]);
+        assert!(!check(&rule, policy).unwrap());
+        // Errors other than type mismatches still fail evaluation.
+        let rule = logical_rule(LogicalOperator::Unspecified, vec![mismatch_rule()]);
+        assert_eq!(
+            check(&rule, policy).unwrap_err(),
+            EvalError::UnsupportedOperator("LOGICAL_OPERATOR_UNSPECIFIED")
+        );
     }
-}
 
-fn get_number(kind: &Kind) -> Result<f64, Status> {
-    match kind {
-        NumberValue(num_value) => Ok(*num_value),
-        _ => Err(Status::invalid_argument("type mismatch")),
+    #[test]
+    fn test_contained_within() {
+        let list = |values: Vec<prost_types::value::Kind>| {
+            prost_types::value::Kind::ListValue(prost_types::ListValue {
+                values: values
+                    .into_iter()
+                    .map(|kind| prost_types::Value { kind: Some(kind) })
+                    .collect(),
+            })
+        };
+        let rule = Rule {
+            rule: Some(AtomRule(Atom {
+                context_key: String::from("key"),
+                comparison_value: Some(prost_types::Value {
+                    kind: Some(list(vec![
+                        prost_types::value::Kind::StringValue(String::from("a")),
+                        NumberValue(1.0),
+                        NumberValue(-0.0),
+                        NumberValue(f64::NAN),
+                        prost_types::value::Kind::BoolValue(true),
+                        prost_types::value::Kind::NullValue(0),
+                    ])),
+                }),
+                comparison_operator: CmpOp::ContainedWithin.into(),
+            })),
+            ..Default::default()
+        };
+        let mut interner = Interner::default();
+        let compiled = compile_rule(&rule, &mut Compiler::new("ns", "feature", &mut interner));
+        let check = |kind: LekkoKind| {
+            let context = HashMap::from([(String::from("key"), LekkoValue { kind: Some(kind) })]);
+            check_rule(&compiled, &context, &EvalContext::default()).unwrap()
+        };
+        assert!(check(LekkoKind::StringValue(String::from("a"))));
+        assert!(!check(LekkoKind::StringValue(String::from("b"))));
+        assert!(check(LekkoKind::IntValue(1)));
+        assert!(check(LekkoKind::DoubleValue(1.0)));
+        assert!(!check(LekkoKind::IntValue(2)));
+        // 0.0 and -0.0 are equal
+        assert!(check(LekkoKind::IntValue(0)));
+        assert!(check(LekkoKind::DoubleValue(0.0)));
+        assert!(!check(LekkoKind::DoubleValue(f64::NAN)));
+        assert!(check(LekkoKind::BoolValue(true)));
+        assert!(!check(LekkoKind::BoolValue(false)));
+        // Missing keys are never contained in the list
+        assert!(!check_rule(&compiled, &HashMap::new(), &EvalContext::default()).unwrap());
     }
-}
 
-fn get_lekko_number(kind: &LekkoKind) -> Result<f64, Status> {
-    match kind {
-        LekkoKind::IntValue(int_value) => Ok(*int_value as f64),
-        LekkoKind::DoubleValue(double_value) => Ok(*double_value),
-        _ => Err(Status::invalid_argument("type mismatch")),
+    fn check_version(
+        ctx_version: LekkoKind,
+        op: CmpOp,
+        rule_version: &str,
+        policy: TypeMismatchPolicy,
+    ) -> Result<bool, EvalError> {
+        let rule = Rule {
+            rule: Some(AtomRule(Atom {
+                context_key: String::from("app_version"),
+                comparison_value: Some(prost_types::Value {
+                    kind: Some(prost_types::value::Kind::StringValue(
+                        rule_version.to_owned(),
+                    )),
+                }),
+                comparison_operator: op.into(),
+            })),
+            ..Default::default()
+        };
+        let mut interner = Interner::default();
+        let compiled = compile_rule(&rule, &mut Compiler::new("ns", "feature", &mut interner));
+        let context = HashMap::from([(
+            String::from("app_version"),
+            LekkoValue {
+                kind: Some(ctx_version),
+            },
+        )]);
+        let eval_context = EvalContext {
+            type_mismatch_policy: policy,
+            ..Default::default()
+        };
+        check_rule(&compiled, &context, &eval_context)
     }
-}
 
-fn get_string(kind: &Kind) -> Result<String, Status> {
-    match kind {
-        StringValue(str) => Ok(str.clone()),
-        _ => Err(Status::invalid_argument("type mismatch")),
+    // NOTE: to test consistency of version comparisons cross-platform, test cases
+    // (data and expected evaluation results) should be identical across SDKs.
+    #[test]
+    fn test_semver_cmp() {
+        use CmpOp::{GreaterThan, GreaterThanOrEquals, LessThan, LessThanOrEquals};
+        let test_cases = [
+            ("4.12.3", GreaterThanOrEquals, "4.10.0", true),
+            ("4.12.3", LessThan, "4.10.0", false),
+            ("4.9.0", LessThan, "4.10.0", true),
+            ("4.10.0", GreaterThanOrEquals, "4.10.0", true),
+            ("4.10.0", GreaterThan, "4.10.0", false),
+            ("4.10.0", LessThanOrEquals, "4.10.0", true),
+            ("10.0.0", GreaterThan, "9.99.99", true),
+            ("0.0.1", LessThan, "0.1.0", true),
+            // pre-releases have lower precedence than their release
+            ("1.0.0-alpha", LessThan, "1.0.0", true),
+            ("1.0.0-rc.1", GreaterThanOrEquals, "1.0.0", false),
+            ("1.0.0-rc.1", GreaterThan, "0.9.9", true),
+            // pre-release ordering from the semver 2.0.0 specification
+            ("1.0.0-alpha", LessThan, "1.0.0-alpha.1", true),
+            ("1.0.0-alpha.1", LessThan, "1.0.0-alpha.beta", true),
+            ("1.0.0-alpha.beta", LessThan, "1.0.0-beta", true),
+            ("1.0.0-beta", LessThan, "1.0.0-beta.2", true),
+            ("1.0.0-beta.2", LessThan, "1.0.0-beta.11", true),
+            ("1.0.0-beta.11", LessThan, "1.0.0-rc.1", true),
+            ("1.0.0-rc.1", LessThan, "1.0.0", true),
+            ("1.0.0-beta.11", GreaterThan, "1.0.0-beta.2", true),
+            // build metadata is ignored
+            ("1.0.0+build.5", GreaterThanOrEquals, "1.0.0", true),
+            ("1.0.0+build.5", LessThanOrEquals, "1.0.0+build.1", true),
+            ("1.0.0+build.5", GreaterThan, "1.0.0", false),
+        ];
+        for (ctx_version, op, rule_version, expected) in test_cases {
+            let result = check_version(
+                LekkoKind::StringValue(ctx_version.to_owned()),
+                op,
+                rule_version,
+                TypeMismatchPolicy::Error,
+            );
+            assert_eq!(
+                result.unwrap(),
+                expected,
+                "{ctx_version} {} {rule_version}",
+                op.as_str_name()
+            );
+        }
     }
-}
 
-fn get_lekko_string(kind: &LekkoKind) -> Result<String, Status> {
-    match kind {
-        LekkoKind::StringValue(str_value) => Ok(str_value.clone()),
-        _ => Err(Status::invalid_argument("type mismatch")),
+    #[test]
+    fn test_semver_cmp_invalid() {
+        let invalid = |ctx_version: LekkoKind, rule_version: &str| {
+            let err = check_version(
+                ctx_version.clone(),
+                CmpOp::GreaterThan,
+                rule_version,
+                TypeMismatchPolicy::Error,
+            )
+            .unwrap_err();
+            assert!(matches!(err, EvalError::TypeMismatch { .. }), "{err:?}");
+            // invalid versions are type mismatches, so they can be treated as false
+            assert!(!check_version(
+                ctx_version,
+                CmpOp::GreaterThan,
+                rule_version,
+                TypeMismatchPolicy::False
+            )
+            .unwrap());
+        };
+        invalid(LekkoKind::StringValue(String::from("latest")), "1.0.0");
+        invalid(LekkoKind::StringValue(String::from("4.12")), "1.0.0");
+        invalid(LekkoKind::StringValue(String::from("v4.12.3")), "1.0.0");
+        invalid(LekkoKind::IntValue(4), "1.0.0");
+        invalid(LekkoKind::StringValue(String::from("4.12.3")), "4.10");
     }
 }
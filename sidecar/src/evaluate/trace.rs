@@ -0,0 +1,285 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use crate::gen::cli::lekko::feature::v1beta1::Constraint;
+use crate::gen::sdk::lekko::client::v1beta1::Value;
+use crate::gen::sidecar::lekko::sidecar::v1beta1::{CheckTrace, CheckType, ConstraintTrace};
+
+use super::error::EvalError;
+use super::functions::{Bucket, EvaluateTo};
+use super::rules::Atom;
+
+// Tracer records how an evaluation arrived at its result, so that it can be
+// explained to the user. Evaluation records every constraint it visits, along
+// with the atoms and function calls it checked while evaluating the
+// constraint's rule.
+//
+// A tracer is attached to an evaluation through EvalContext. Nested evaluations
+// (e.g. through evaluate_to) are not traced, only their result is.
+#[derive(Default)]
+pub struct Tracer {
+    constraints: RefCell<Vec<ConstraintTrace>>,
+    // Checks made for the constraint that is currently being evaluated.
+    checks: RefCell<Vec<CheckTrace>>,
+}
+
+impl Tracer {
+    // Returns the trace of every visited constraint, in the order they were visited.
+    pub fn into_constraints(self) -> Vec<ConstraintTrace> {
+        self.constraints.into_inner()
+    }
+
+    pub(crate) fn record_check(&self, check: CheckTrace) {
+        self.checks.borrow_mut().push(check);
+    }
+
+    // Records a visited constraint, along with all checks recorded since the
+    // previous constraint. Returns a handle to the recorded constraint.
+    pub(crate) fn record_constraint(
+        &self,
+        path: &[usize],
+        constraint: &Constraint,
+        has_value: bool,
+        result: &Result<bool, EvalError>,
+    ) -> usize {
+        let mut constraints = self.constraints.borrow_mut();
+        constraints.push(ConstraintTrace {
+            path: path.iter().map(|i| *i as i32).collect(),
+            rule: constraint.rule.clone(),
+            passed: *result.as_ref().unwrap_or(&false),
+            checks: self.checks.take(),
+            has_value,
+            fell_back: false,
+            error: error_message(result),
+        });
+        constraints.len() - 1
+    }
+
+    pub(crate) fn mark_fell_back(&self, handle: usize) {
+        if let Some(constraint) = self.constraints.borrow_mut().get_mut(handle) {
+            constraint.fell_back = true;
+        }
+    }
+}
+
+pub(crate) fn atom_check(
+    atom: &Atom,
+    context: &HashMap<String, Value>,
+    result: &Result<bool, EvalError>,
+) -> CheckTrace {
+    CheckTrace {
+        r#type: CheckType::Atom.into(),
+        context_key: atom.context_key.to_string(),
+        context_value: context.get(&*atom.context_key).cloned(),
+        comparison_operator: atom.comparison_operator.as_str_name().to_owned(),
+        comparison_value: atom.comparison_value.clone(),
+        result: *result.as_ref().unwrap_or(&false),
+        error: error_message(result),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn bucket_check(
+    bucket: &Bucket,
+    context: &HashMap<String, Value>,
+    result: &Result<bool, EvalError>,
+) -> CheckTrace {
+    CheckTrace {
+        r#type: CheckType::Bucket.into(),
+        context_key: bucket.context_key.to_string(),
+        context_value: context.get(&*bucket.context_key).cloned(),
+        bucket_threshold: bucket.threshold,
+        result: *result.as_ref().unwrap_or(&false),
+        error: error_message(result),
+        ..Default::default()
+    }
+}
+
+pub(crate) fn evaluate_to_check(
+    evaluate_to: &EvaluateTo,
+    result: &Result<bool, EvalError>,
+) -> CheckTrace {
+    CheckTrace {
+        r#type: CheckType::EvaluateTo.into(),
+        comparison_value: evaluate_to.config_value.clone(),
+        config_name: evaluate_to.config_name.clone(),
+        result: *result.as_ref().unwrap_or(&false),
+        error: error_message(result),
+        ..Default::default()
+    }
+}
+
+fn error_message(result: &Result<bool, EvalError>) -> String {
+    match result {
+        Ok(_) => String::new(),
+        Err(e) => e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluate::evaluator::{evaluate, EvalContext};
+    use crate::evaluate::plan::{CompiledFeature, Interner};
+    use crate::gen::cli::lekko::feature::v1beta1::{Feature, Tree};
+    use crate::gen::cli::lekko::rules::v1beta3::{
+        rule::Rule as RuleKind, Atom, ComparisonOperator, Rule,
+    };
+    use crate::gen::sdk::lekko::client::v1beta1::value::Kind;
+    use prost_types::Any;
+
+    fn bool_any(b: bool) -> Any {
+        Any {
+            type_url: String::from("type.googleapis.com/google.protobuf.BoolValue"),
+            value: crate::types::to_any(&b).value,
+        }
+    }
+
+    fn atom_rule(key: &str, op: ComparisonOperator, value: prost_types::value::Kind) -> Rule {
+        Rule {
+            rule: Some(RuleKind::Atom(Atom {
+                context_key: key.to_owned(),
+                comparison_value: Some(prost_types::Value { kind: Some(value) }),
+                comparison_operator: op.into(),
+            })),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_trace_constraints() {
+        // country == "US" -> true
+        // true (no value)
+        //   age > 100 -> true
+        let feature = Feature {
+            key: String::from("feature"),
+            tree: Some(Tree {
+                default: Some(bool_any(false)),
+                constraints: vec![
+                    Constraint {
+                        rule: String::from("country == \"US\""),
+                        value: Some(bool_any(true)),
+                        rule_ast_new: Some(atom_rule(
+                            "country",
+                            ComparisonOperator::Equals,
+                            prost_types::value::Kind::StringValue(String::from("US")),
+                        )),
+                        ..Default::default()
+                    },
+                    Constraint {
+                        rule: String::from("true"),
+                        rule_ast_new: Some(Rule {
+                            rule: Some(RuleKind::BoolConst(true)),
+                            ..Default::default()
+                        }),
+                        constraints: vec![Constraint {
+                            rule: String::from("age > 100"),
+                            value: Some(bool_any(true)),
+                            rule_ast_new: Some(atom_rule(
+                                "age",
+                                ComparisonOperator::GreaterThan,
+                                prost_types::value::Kind::NumberValue(100.0),
+                            )),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let context = HashMap::from([
+            (
+                String::from("country"),
+                Value {
+                    kind: Some(Kind::StringValue(String::from("CA"))),
+                },
+            ),
+            (
+                String::from("age"),
+                Value {
+                    kind: Some(Kind::IntValue(30)),
+                },
+            ),
+        ]);
+        let tracer = Tracer::default();
+        let eval_context = EvalContext {
+            namespace: String::from("ns"),
+            feature_name: String::from("feature"),
+            tracer: Some(&tracer),
+            ..Default::default()
+        };
+        let feature = CompiledFeature::new("ns", feature, &mut Interner::default());
+        let (value, path) = evaluate(&feature, &context, &eval_context).unwrap();
+        assert_eq!(value, bool_any(false));
+        assert!(path.is_empty());
+
+        let constraints = tracer.into_constraints();
+        assert_eq!(constraints.len(), 3);
+
+        assert_eq!(constraints[0].path, vec![0]);
+        assert!(!constraints[0].passed);
+        assert!(!constraints[0].fell_back);
+        assert_eq!(constraints[0].checks.len(), 1);
+        let check = &constraints[0].checks[0];
+        assert_eq!(check.r#type(), CheckType::Atom);
+        assert_eq!(check.context_key, "country");
+        assert_eq!(check.context_value, context.get("country").cloned());
+        assert_eq!(check.comparison_operator, "COMPARISON_OPERATOR_EQUALS");
+        assert!(!check.result);
+
+        // The second constraint passes, but has no value and none of its children
+        // pass, so evaluation falls back to the default value.
+        assert_eq!(constraints[1].path, vec![1]);
+        assert!(constraints[1].passed);
+        assert!(!constraints[1].has_value);
+        assert!(constraints[1].fell_back);
+        assert!(constraints[1].checks.is_empty());
+
+        assert_eq!(constraints[2].path, vec![1, 0]);
+        assert_eq!(constraints[2].rule, "age > 100");
+        assert!(!constraints[2].passed);
+        assert_eq!(constraints[2].checks.len(), 1);
+        assert_eq!(constraints[2].checks[0].context_key, "age");
+    }
+
+    #[test]
+    fn test_trace_error() {
+        let feature = Feature {
+            key: String::from("feature"),
+            tree: Some(Tree {
+                default: Some(bool_any(false)),
+                constraints: vec![Constraint {
+                    value: Some(bool_any(true)),
+                    rule_ast_new: Some(atom_rule(
+                        "country",
+                        ComparisonOperator::LessThan,
+                        prost_types::value::Kind::NumberValue(1.0),
+                    )),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let context = HashMap::from([(
+            String::from("country"),
+            Value {
+                kind: Some(Kind::StringValue(String::from("CA"))),
+            },
+        )]);
+        let tracer = Tracer::default();
+        let eval_context = EvalContext {
+            tracer: Some(&tracer),
+            ..Default::default()
+        };
+        let feature = CompiledFeature::new("ns", feature, &mut Interner::default());
+        let err = evaluate(&feature, &context, &eval_context).unwrap_err();
+
+        let constraints = tracer.into_constraints();
+        assert_eq!(constraints.len(), 1);
+        assert!(!constraints[0].passed);
+        assert_eq!(constraints[0].error, err.to_string());
+        assert_eq!(constraints[0].checks[0].error, err.to_string());
+    }
+}
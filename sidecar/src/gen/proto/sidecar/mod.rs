@@ -0,0 +1,10 @@
+// @generated
+pub mod lekko {
+    pub mod sidecar {
+        // @@protoc_insertion_point(attribute:lekko.sidecar.v1beta1)
+        pub mod v1beta1 {
+            include!("lekko.sidecar.v1beta1.rs");
+            // @@protoc_insertion_point(lekko.sidecar.v1beta1)
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod admin_service;
+pub mod backoff;
 pub mod config_service;
 pub mod distro_service;
 pub mod evaluate;
@@ -5,5 +7,10 @@ pub mod gen;
 pub mod logging;
 pub mod metrics;
 pub mod repofs;
+pub mod repogit;
+pub mod repos;
+pub mod snapshot_cache;
+pub mod source;
 pub mod store;
 pub mod types;
+pub mod validation;
@@ -3,27 +3,40 @@ use hyper_rustls::HttpsConnectorBuilder;
 use metrics::counter;
 use sidecar::gen::cli::lekko::backend::v1beta1::distribution_service_client::DistributionServiceClient;
 use sidecar::gen::cli::lekko::backend::v1beta1::distribution_service_server::DistributionServiceServer;
-use sidecar::gen::cli::lekko::backend::v1beta1::GetRepositoryContentsRequest;
+use sidecar::gen::cli::lekko::backend::v1beta1::GetRepositoryContentsResponse;
 use sidecar::gen::cli::lekko::backend::v1beta1::RegisterClientRequest;
 use sidecar::gen::cli::lekko::backend::v1beta1::RepositoryKey;
 use sidecar::gen::sdk::lekko::client::v1beta1::configuration_service_server::ConfigurationServiceServer;
+use sidecar::gen::sidecar::lekko::sidecar::v1beta1::admin_service_server::AdminServiceServer;
 use sidecar::repofs::RepoFS;
+use sidecar::repogit::RepoGit;
+use sidecar::repos::{Repo, Repos};
+use sidecar::snapshot_cache::SnapshotCache;
 
+use futures::future::{select_all, FutureExt};
+use hyper::client::HttpConnector;
 use hyper::{http::Request, Body};
+use hyper_rustls::HttpsConnector;
 use log::log;
+use sidecar::admin_service;
 use sidecar::config_service;
 use sidecar::distro_service;
 use sidecar::logging;
 use sidecar::metrics::Metrics;
 use sidecar::metrics::RuntimeMetrics;
-use sidecar::store::Store;
-use sidecar::types::{add_api_key, ConnectionCredentials, Mode};
+use sidecar::source::backend::get_repo_contents_remote;
+use sidecar::source::{BackendSource, ConfigSource, GitSource, SnapshotFileSource};
+use sidecar::store::{NamespaceFilter, Store};
+use sidecar::types::{
+    add_api_key, ConnectionCredentials, Mode, SessionKey, TypeMismatchPolicy, WatchMode,
+};
 use std::fmt::Debug;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::signal::unix::SignalKind;
 use tokio::time::sleep;
+use tonic::body::BoxBody;
 use tonic::codegen::CompressionEncoding;
 use tonic::metadata::{Ascii, MetadataValue};
 use tonic::transport::{Server, Uri};
@@ -31,7 +44,7 @@ use tower_http::{
     trace::{DefaultMakeSpan, DefaultOnFailure, TraceLayer},
     LatencyUnit,
 };
-use tracing::{info, Level, Span};
+use tracing::{error, info, warn, Level, Span};
 
 // Struct containing all the cmd-line args we accept
 #[derive(Parser)]
@@ -56,11 +69,17 @@ struct Args {
     /// Address to bind to on current host.
     metrics_bind_addr: String,
 
+    #[arg(long, default_value_t=String::from("127.0.0.1:50052"))]
+    /// Address to serve the admin service on. It can pin the served version, so it
+    /// is only reachable from the host by default.
+    admin_bind_addr: String,
+
     #[arg(value_enum, long, default_value_t, verbatim_doc_comment)]
     /// Mode can be one of:
     ///   default - initialize from a bootstrap, poll local state from remote and evaluate locally.
     ///   consistent - always evaluate using the latest value of a flag from remote.
-    ///   static - operate off of a config repo found on disk at repo_path.{n}
+    ///   static - operate off of a config repo found on disk at repo_path.
+    ///   snapshot - operate off of a snapshot file found on disk at snapshot_path.{n}
     mode: Mode,
 
     #[arg(short, long, value_parser=parse_duration, default_value="15s")]
@@ -68,21 +87,292 @@ struct Args {
     /// If this duration is too short, Lekko may apply rate limits.
     poll_interval: Duration,
 
-    #[arg(short, long, default_value = "")]
-    /// Absolute path to the directory on disk that contains the .git folder.
-    /// This is required for static mode.
-    repo_path: String,
+    #[arg(long, value_parser=parse_duration, default_value="10m")]
+    /// How long polls for new versions can keep failing before the configuration
+    /// service is reported as not serving, since the served config may be that stale.
+    max_staleness: Duration,
 
-    #[arg(short = 'u', long, default_value = "")]
+    #[arg(short, long, value_delimiter = ',')]
+    /// Absolute path to the directory on disk that contains the .git folder, or to a bare
+    /// repository if git_ref is set.
+    /// This is required for static mode. Comma separated to serve several repos.
+    repo_path: Vec<String>,
+
+    #[arg(short = 'u', long, value_delimiter = ',')]
     /// The url for the repo in "owner_name/repo_name" format, such as:
     /// lekkodev/example, representing github.com/lekkodev/example. This is required for default backend.
-    repo_url: String,
+    /// Comma separated to serve several repos, e.g. a shared platform repo and a team repo.
+    /// Requests are routed by the repo key they carry.
+    repo_url: Vec<String>,
+
+    #[arg(long, value_delimiter = ',')]
+    /// Path to a snapshot file, in the format that is persisted to cache_dir.
+    /// This is required for snapshot mode, with a repo_url for each snapshot, in the same order.
+    snapshot_path: Vec<String>,
+
+    #[arg(value_enum, long, default_value_t, verbatim_doc_comment)]
+    /// How to evaluate a rule that compares a context value of the wrong type:
+    ///   error - fail the evaluation.
+    ///   false - treat the comparison as not passing, and continue evaluating.
+    type_mismatch_policy: TypeMismatchPolicy,
+
+    #[arg(long)]
+    /// Directory to persist the last repository contents loaded from Lekko to.
+    /// In default mode, the sidecar starts from them if Lekko is unreachable.
+    cache_dir: Option<String>,
+
+    #[arg(long, value_delimiter = ',')]
+    /// Comma separated namespaces to load, such as: default,payments.
+    /// Only these namespaces are fetched, kept and served. Loads every namespace if unset.
+    namespaces: Vec<String>,
+
+    #[arg(long, default_value_t = 10)]
+    /// How many of the most recently loaded versions of the repository to retain,
+    /// so that they can be inspected or pinned through the admin service.
+    snapshot_history: usize,
+
+    #[arg(value_enum, long, default_value_t, verbatim_doc_comment)]
+    /// How to watch repo_path or snapshot_path for changes in static or snapshot mode:
+    ///   native - use filesystem notifications. Changes made by git-sync are
+    ///            picked up when it swaps the contents link to a new checkout.
+    ///   poll - scan the repo every second, for filesystems without notifications.
+    watch_mode: WatchMode,
+
+    #[arg(long, num_args = 0..=1, default_missing_value = "HEAD")]
+    /// In static mode, load each repo at this ref or commit, such as HEAD or origin/main,
+    /// straight from the git object database rather than the working tree. Every load is
+    /// then consistent with one commit, and repo_path may be a bare repository.
+    /// Defaults to HEAD if given without a value.
+    git_ref: Option<String>,
 }
 
 impl Debug for Args {
     // We manually implement Debug in order to avoid printing the api key.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{{ lekko_addr: {}, bind_addr: {} api_key: {:?}, metrics_bind_addr: {}, mode: {:?}, poll_interval: {:?}, repo_path: {}, repo_url: {} }}", self.lekko_addr, self.bind_addr, self.api_key.as_ref().map(|_| "Some(<lekko api key>)"), self.metrics_bind_addr, self.mode, self.poll_interval, self.repo_path, self.repo_url))
+        f.write_fmt(format_args!("{{ lekko_addr: {}, bind_addr: {} api_key: {:?}, metrics_bind_addr: {}, admin_bind_addr: {}, mode: {:?}, poll_interval: {:?}, max_staleness: {:?}, repo_path: {:?}, repo_url: {:?}, snapshot_path: {:?}, type_mismatch_policy: {:?}, cache_dir: {:?}, namespaces: {:?}, snapshot_history: {}, watch_mode: {:?}, git_ref: {:?} }}", self.lekko_addr, self.bind_addr, self.api_key.as_ref().map(|_| "Some(<lekko api key>)"), self.metrics_bind_addr, self.admin_bind_addr, self.mode, self.poll_interval, self.max_staleness, self.repo_path, self.repo_url, self.snapshot_path, self.type_mismatch_policy, self.cache_dir, self.namespaces, self.snapshot_history, self.watch_mode, self.git_ref))
+    }
+}
+
+// How often the health loop checks whether the stores are stale.
+const STALENESS_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+// What the sidecar starts serving a repository from.
+struct Bootstrap {
+    repo_key: RepositoryKey,
+    contents: GetRepositoryContentsResponse,
+    // Static and snapshot mode watch for changes before the contents are loaded, so
+    // that no change after them is missed. Default mode polls once it has registered.
+    source: Option<Box<dyn ConfigSource>>,
+    snapshot_cache: Option<SnapshotCache>,
+    // Whether the contents came from the snapshot cache because Lekko was unreachable.
+    from_cache: bool,
+}
+
+async fn bootstrap_static(
+    args: &Args,
+    repo_path: &str,
+    namespace_filter: &NamespaceFilter,
+) -> Bootstrap {
+    let (repo_key, source) = match &args.git_ref {
+        Some(git_ref) => (
+            RepoGit::new(repo_path.to_owned(), git_ref.to_owned())
+                .expect("invalid repository")
+                .repo_key(),
+            GitSource::at_rev(repo_path.to_owned(), git_ref.to_owned(), args.watch_mode),
+        ),
+        None => (
+            RepoFS::new(repo_path.to_owned())
+                .expect("invalid repository")
+                .repo_key(),
+            GitSource::new(repo_path.to_owned(), args.watch_mode),
+        ),
+    };
+    let repo_key = repo_key.expect("invalid remote information in repo path");
+    let source = source.expect("error watching repository");
+    let contents = source
+        .load(namespace_filter)
+        .await
+        .expect("error loading info");
+    Bootstrap {
+        repo_key,
+        contents,
+        source: Some(Box::new(source)),
+        snapshot_cache: None,
+        from_cache: false,
+    }
+}
+
+async fn bootstrap_snapshot(
+    args: &Args,
+    repo_url: &str,
+    snapshot_path: &str,
+    namespace_filter: &NamespaceFilter,
+) -> Bootstrap {
+    let source =
+        SnapshotFileSource::new(snapshot_path, args.watch_mode).expect("error watching snapshot");
+    let contents = source
+        .load(namespace_filter)
+        .await
+        .expect("error loading snapshot");
+    Bootstrap {
+        repo_key: parse_repo_url(repo_url),
+        contents,
+        source: Some(Box::new(source)),
+        snapshot_cache: None,
+        from_cache: false,
+    }
+}
+
+async fn bootstrap_remote(
+    args: &Args,
+    repo_url: &str,
+    dist_client: DistributionServiceClient<hyper::Client<HttpsConnector<HttpConnector>, BoxBody>>,
+    namespace_filter: &NamespaceFilter,
+) -> Bootstrap {
+    let api_key = args
+        .api_key
+        .as_ref()
+        .expect("no api key provided in default mode");
+    let repo_key = parse_repo_url(repo_url);
+    let snapshot_cache = args
+        .cache_dir
+        .as_ref()
+        .map(|dir| SnapshotCache::new(dir, &repo_key));
+    let bootstrap_creds = ConnectionCredentials {
+        session_key: SessionKey::default(),
+        repo_key: repo_key.clone(),
+        api_key: api_key.clone(),
+    };
+    let mut from_cache = false;
+    let contents = match get_repo_contents_remote(dist_client, &bootstrap_creds, namespace_filter)
+        .await
+    {
+        Ok(resp) => resp,
+        Err(e) => match snapshot_cache.as_ref().map(SnapshotCache::read) {
+            Some(Ok(cached)) => {
+                warn!(
+                    "error performing initial fetch for {repo_url}: {e:?}, starting from cached snapshot for commit sha {}",
+                    cached.commit_sha
+                );
+                from_cache = true;
+                cached
+            }
+            Some(Err(cache_err)) => panic!(
+                "error performing initial fetch for {repo_url}: {e:?}, and no usable cached snapshot: {cache_err}"
+            ),
+            None => panic!("error performing initial fetch for {repo_url}: {:?}", e),
+        },
+    };
+    Bootstrap {
+        repo_key,
+        contents,
+        source: None,
+        snapshot_cache,
+        from_cache,
+    }
+}
+
+// Registers the repository with Lekko if there is an api key, and starts a store
+// that follows it.
+async fn start_repo(
+    args: &Args,
+    bootstrap: Bootstrap,
+    dist_client: DistributionServiceClient<hyper::Client<HttpsConnector<HttpConnector>, BoxBody>>,
+    namespace_filter: NamespaceFilter,
+    sidecar_version: &str,
+) -> Result<Repo, tonic::Status> {
+    let rk = bootstrap.repo_key;
+    let register_request = RegisterClientRequest {
+        repo_key: Some(rk.clone()),
+        initial_bootstrap_sha: bootstrap.contents.commit_sha.clone(),
+        sidecar_version: sidecar_version.to_owned(),
+        namespace_list: args.namespaces.clone(),
+    };
+    // Set if the sidecar has to register once Lekko is reachable again.
+    let mut pending_registration = None;
+    let conn_creds = match args.api_key.as_ref() {
+        Some(key) => {
+            let res = dist_client
+                .clone()
+                .register_client(add_api_key(register_request.clone(), key.clone()))
+                .await
+                .map(|resp| ConnectionCredentials {
+                    session_key: SessionKey::new(resp.into_inner().session_key),
+                    repo_key: rk.clone(),
+                    api_key: key.clone(),
+                });
+            match (res, &args.mode) {
+                (Ok(conn), _) => Some(conn),
+                // don't panic in static or snapshot mode, sidecar can work without remote
+                (Err(_), Mode::Static | Mode::Snapshot) => Some(ConnectionCredentials {
+                    session_key: SessionKey::default(),
+                    repo_key: rk.clone(),
+                    api_key: key.clone(),
+                }),
+                // Lekko was already unreachable at startup. Keep serving the cached
+                // snapshot, and register before polling once Lekko is back.
+                (Err(err), _) if bootstrap.from_cache => {
+                    warn!("error connecting to remote: {:?}", err);
+                    pending_registration = Some(register_request);
+                    Some(ConnectionCredentials {
+                        session_key: SessionKey::default(),
+                        repo_key: rk.clone(),
+                        api_key: key.clone(),
+                    })
+                }
+                (Err(err), _) => {
+                    panic!("error connecting to remote: {:?}", err);
+                }
+            }
+        }
+        None => None,
+    };
+
+    let source = bootstrap.source.unwrap_or_else(|| {
+        let mut source = BackendSource::new(
+            dist_client.clone(),
+            conn_creds
+                .clone()
+                .expect("no api key provided in default mode"),
+            args.poll_interval,
+            bootstrap.snapshot_cache,
+        );
+        if let Some(request) = pending_registration {
+            source.register_before_polling(request);
+        }
+        Box::new(source)
+    });
+    let store = Arc::new(Store::new(
+        bootstrap.contents,
+        source,
+        namespace_filter,
+        args.snapshot_history,
+    )?);
+    if bootstrap.from_cache {
+        store.mark_from_cache();
+    }
+    let metrics = conn_creds
+        .as_ref()
+        .map(|c| Metrics::new(dist_client, c.api_key.clone(), Some(c.session_key.clone())));
+    Ok(Repo {
+        key: rk,
+        store,
+        metrics,
+        conn_creds,
+    })
+}
+
+// Parses a repo url in "owner_name/repo_name" format.
+fn parse_repo_url(repo_url: &str) -> RepositoryKey {
+    let (owner, repo) = repo_url.split_once('/').unwrap_or_else(|| {
+        panic!(
+            "invalid repo-url: {}, please use the format owner/repo i.e. lekkodev/example",
+            repo_url
+        )
+    });
+    RepositoryKey {
+        owner_name: owner.to_owned(),
+        repo_name: repo.to_owned(),
     }
 }
 
@@ -123,6 +413,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Ok(a) => a,
     };
 
+    let admin_bind_addr = match args.admin_bind_addr.parse::<SocketAddr>() {
+        Err(err) => panic!(
+            "parsing admin_bind_addr {} failed: {err:?}",
+            args.admin_bind_addr
+        ),
+        Ok(a) => a,
+    };
+
     let runtime_metrics = RuntimeMetrics::new(metrics_bind_addr);
     counter!(runtime_metrics.startup_counter, 1);
 
@@ -149,109 +447,77 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .send_compressed(CompressionEncoding::Gzip)
         .accept_compressed(CompressionEncoding::Gzip);
 
-    let (bootstrap_data, rk) = match &args.mode {
+    let namespace_filter = NamespaceFilter::new(args.namespaces.iter().cloned());
+
+    let bootstraps = match &args.mode {
         Mode::Static => {
             if args.repo_path.is_empty() {
                 panic!("repo-path needs to be set in static mode")
             }
-            let bootstrap_data = RepoFS::new(args.repo_path.clone()).expect("invalid repository");
-            let repo_key = bootstrap_data
-                .repo_key()
-                .expect("invalid remote information in repo path");
-            let bootstrap = bootstrap_data.load().expect("error loading info");
-            (bootstrap, repo_key)
+            let mut bootstraps = Vec::new();
+            for repo_path in &args.repo_path {
+                bootstraps.push(bootstrap_static(&args, repo_path, &namespace_filter).await);
+            }
+            bootstraps
+        }
+        Mode::Snapshot => {
+            if args.snapshot_path.is_empty() {
+                panic!("snapshot-path needs to be set in snapshot mode")
+            }
+            if args.repo_url.len() != args.snapshot_path.len() {
+                panic!("each snapshot-path needs a repo-url in snapshot mode, in the same order")
+            }
+            let mut bootstraps = Vec::new();
+            for (repo_url, snapshot_path) in args.repo_url.iter().zip(&args.snapshot_path) {
+                bootstraps.push(
+                    bootstrap_snapshot(&args, repo_url, snapshot_path, &namespace_filter).await,
+                );
+            }
+            bootstraps
         }
         Mode::Default => {
             if args.repo_url.is_empty() {
                 panic!("repo-url needs to be set in default mode")
             }
-            let api_key = args
-                .api_key
-                .as_ref()
-                .expect("no api key provided in default mode");
-            let (owner, repo) = args.repo_url.split_once('/').unwrap_or_else(|| {
-                panic!(
-                    "invalid repo-url: {}, please use the format owner/repo i.e. lekkodev/example",
-                    args.repo_url
-                )
-            });
-
-            let repo_key = RepositoryKey {
-                owner_name: owner.to_owned(),
-                repo_name: repo.to_owned(),
-            };
-
-            let bootstrap = dist_client
-                .clone()
-                .get_repository_contents(add_api_key(
-                    GetRepositoryContentsRequest {
-                        repo_key: Some(repo_key.clone()),
-                        feature_name: "".to_string(),
-                        namespace_name: "".to_string(),
-                        session_key: "".to_string(),
-                    },
-                    api_key.clone(),
-                ))
-                .await
-                .unwrap_or_else(|e| panic!("error performing initial fetch: {:?}", e))
-                .into_inner();
-            (bootstrap, repo_key)
-        }
-    };
-
-    let api_key = args.api_key.as_ref();
-
-    let conn_creds = match api_key {
-        Some(key) => {
-            let res = dist_client
-                .clone()
-                .register_client(add_api_key(
-                    RegisterClientRequest {
-                        repo_key: Some(rk.clone()),
-                        initial_bootstrap_sha: bootstrap_data.commit_sha.clone(),
-                        sidecar_version: sidecar_version.clone(),
-                        namespace_list: vec![],
-                    },
-                    key.clone(),
-                ))
-                .await
-                .map(|resp| ConnectionCredentials {
-                    session_key: resp.into_inner().session_key,
-                    repo_key: rk.clone(),
-                    api_key: key.clone(),
-                });
-            match (res, &args.mode) {
-                (Ok(conn), _) => Some(conn),
-                // don't panic in static mode, sidecar can work without remote
-                (Err(_), Mode::Static) => Some(ConnectionCredentials {
-                    session_key: "".to_string(),
-                    repo_key: rk.clone(),
-                    api_key: key.clone(),
-                }),
-                (Err(err), _) => {
-                    panic!("error connecting to remote: {:?}", err);
-                }
+            let mut bootstraps = Vec::new();
+            for repo_url in &args.repo_url {
+                bootstraps.push(
+                    bootstrap_remote(&args, repo_url, dist_client.clone(), &namespace_filter).await,
+                );
             }
+            bootstraps
         }
-        None => None,
     };
 
-    let store = Arc::new(Store::new(
-        dist_client.clone(),
-        bootstrap_data,
-        conn_creds.clone(),
-        args.poll_interval,
-        args.mode.to_owned(),
-        args.repo_path,
-    ));
-
-    let session_key_opt = conn_creds.as_ref().map(|c| c.session_key.clone());
+    let mut repos = Vec::new();
+    for bootstrap in bootstraps {
+        repos.push(
+            start_repo(
+                &args,
+                bootstrap,
+                dist_client.clone(),
+                namespace_filter.clone(),
+                &sidecar_version,
+            )
+            .await?,
+        );
+    }
+    let repos = Arc::new(Repos::new(repos)?);
+    let stores: Vec<_> = repos.iter().map(|repo| repo.store.clone()).collect();
+
     let config_service: ConfigurationServiceServer<config_service::Service> =
         ConfigurationServiceServer::new(config_service::Service {
-            store: store.clone(),
+            repos: repos.clone(),
             mode: args.mode,
-            metrics: api_key.map(|k| Metrics::new(dist_client.clone(), k.clone(), session_key_opt)),
-            repo_key: rk.clone(),
+            type_mismatch_policy: args.type_mismatch_policy,
+        })
+        .send_compressed(CompressionEncoding::Gzip)
+        .accept_compressed(CompressionEncoding::Gzip);
+
+    let admin_service: AdminServiceServer<admin_service::Service> =
+        AdminServiceServer::new(admin_service::Service {
+            repos: repos.clone(),
+            type_mismatch_policy: args.type_mismatch_policy,
         })
         .send_compressed(CompressionEncoding::Gzip)
         .accept_compressed(CompressionEncoding::Gzip);
@@ -259,9 +525,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let distro_service: DistributionServiceServer<distro_service::Service> =
         DistributionServiceServer::new(distro_service::Service {
             distro_client: dist_client,
-            store,
-            repo_key: rk,
-            conn_creds,
+            repos,
             sidecar_version,
         })
         .send_compressed(CompressionEncoding::Gzip)
@@ -275,6 +539,65 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .set_serving::<DistributionServiceServer<distro_service::Service>>()
         .await;
 
+    // Stop serving config once the source of any store keeps failing, or its polls
+    // have been failing for longer than max_staleness, so that orchestrators notice
+    // rather than the sidecar serving stale config forever.
+    let mut config_health = health_reporter.clone();
+    let max_staleness = args.max_staleness;
+    tokio::spawn(async move {
+        let mut store_healths: Vec<_> = stores
+            .iter()
+            .map(|store| store.subscribe_health())
+            .collect();
+        let mut serving = None;
+        loop {
+            let healthy = store_healths
+                .iter_mut()
+                .all(|health| *health.borrow_and_update());
+            let stale = stores.iter().any(|store| store.is_stale(max_staleness));
+            let healthy = healthy && !stale;
+            if serving != Some(healthy) {
+                if healthy {
+                    config_health
+                        .set_serving::<ConfigurationServiceServer<config_service::Service>>()
+                        .await;
+                } else {
+                    if stale {
+                        warn!("polls have failed for longer than {max_staleness:?}, not serving");
+                    }
+                    config_health
+                        .set_not_serving::<ConfigurationServiceServer<config_service::Service>>()
+                        .await;
+                }
+                serving = Some(healthy);
+            }
+            let changes = store_healths
+                .iter_mut()
+                .map(|health| health.changed().boxed());
+            tokio::select! {
+                (result, ..) = select_all(changes) => {
+                    if result.is_err() {
+                        return;
+                    }
+                }
+                // Staleness grows without any change to the stores' health.
+                _ = sleep(STALENESS_CHECK_INTERVAL) => (),
+            }
+        }
+    });
+
+    // The admin service is served separately from the services that SDKs call, so
+    // that it can be kept off the network.
+    tokio::spawn(async move {
+        if let Err(err) = Server::builder()
+            .add_service(admin_service)
+            .serve(admin_bind_addr)
+            .await
+        {
+            error!("admin service on {admin_bind_addr} failed: {err:?}");
+        }
+    });
+
     Server::builder()
         .layer(
             TraceLayer::new_for_grpc()
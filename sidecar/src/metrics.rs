@@ -9,6 +9,7 @@ use hyper::client::HttpConnector;
 use hyper_rustls::HttpsConnector;
 use itertools::Itertools;
 use log::{debug, error, warn};
+use metrics::counter;
 use metrics_exporter_prometheus::PrometheusBuilder;
 use prost_types::Timestamp;
 use tokio::{
@@ -18,10 +19,11 @@ use tokio::{
 use tonic::{
     body::BoxBody,
     metadata::{Ascii, MetadataValue},
-    Request,
+    Code, Request, Status,
 };
 
 use crate::{
+    evaluate::error::EvalError,
     gen::cli::lekko::backend::v1beta1::{
         distribution_service_client::DistributionServiceClient, ContextKey, FlagEvaluationEvent,
         SendFlagEvaluationMetricsRequest,
@@ -31,7 +33,7 @@ use crate::{
         Value,
     },
     store::FeatureData,
-    types::{FeatureRequestParams, APIKEY},
+    types::{FeatureRequestParams, SessionKey, APIKEY},
 };
 
 // Component responsible for receiving evaluation metrics as they come in
@@ -51,7 +53,7 @@ impl Metrics {
             hyper::Client<HttpsConnector<HttpConnector>, BoxBody>,
         >,
         api_key: MetadataValue<Ascii>,
-        session_key: Option<String>,
+        session_key: Option<SessionKey>,
     ) -> Self {
         // create a sync channel to send and receive metrics.
         // approximate size of each event is 1KB. this buffer is sized to not exceed 1MB total memory.
@@ -76,10 +78,10 @@ impl Metrics {
         let event = FlagEvaluationEvent {
             client_event_time: Some(Timestamp::from(SystemTime::now())),
             repo_key: Some(feature_params.rk.clone()),
-            commit_sha: feature_data.commit_sha.clone(),
-            feature_sha: feature_data.feature_sha.clone(),
+            commit_sha: feature_data.commit_sha.to_string(),
+            feature_sha: feature_data.info.version.clone(),
             namespace_name: feature_params.namespace.to_owned(),
-            feature_name: feature_data.feature.key.clone(),
+            feature_name: feature_data.info.feature.proto().key.clone(),
             context_keys: context
                 .iter()
                 .map(|(k, v)| ContextKey {
@@ -104,7 +106,7 @@ impl Metrics {
             hyper::Client<HttpsConnector<HttpConnector>, BoxBody>,
         >,
         api_key: MetadataValue<Ascii>,
-        session_key: Option<String>,
+        session_key: Option<SessionKey>,
     ) {
         // Pool of futures allows this thread to not block on I/O, sending out multiple
         // metrics at once while also receiving from the channel.
@@ -148,12 +150,12 @@ impl Metrics {
         >,
         events: Vec<TrackFlagEvaluationEvent>,
         api_key: MetadataValue<Ascii>,
-        session_key: Option<String>,
+        session_key: Option<SessionKey>,
     ) -> Result<(), tonic::Status> {
         debug!("sending {} flag evaluation metrics to lekko", events.len());
         let mut req = Request::new(SendFlagEvaluationMetricsRequest {
             events: events.into_iter().map(|event| event.event).collect(),
-            session_key: session_key.unwrap_or_default(),
+            session_key: session_key.map(|k| k.get()).unwrap_or_default(),
         });
         req.metadata_mut().append(APIKEY, api_key);
         dist_client.send_flag_evaluation_metrics(req).await?;
@@ -188,3 +190,41 @@ impl RuntimeMetrics {
         }
     }
 }
+
+// Set to 1 while the sidecar serves a cached snapshot because it can't reach Lekko.
+pub const DEGRADED_GAUGE: &str = "lekko_sidecar_degraded";
+
+// Set to 1 while the sidecar serves a pinned version instead of the latest one.
+pub const PINNED_GAUGE: &str = "lekko_sidecar_pinned";
+
+// Counter of failed polls for new versions, labeled with the stage that failed:
+// version, contents or load.
+pub const POLL_FAILURE_COUNTER: &str = "lekko_sidecar_poll_failure_counter";
+
+// Number of polls that failed since the last successful one.
+pub const POLL_CONSECUTIVE_FAILURES_GAUGE: &str = "lekko_sidecar_poll_consecutive_failures";
+
+// When the last successful poll finished, in seconds since the unix epoch.
+pub const LAST_SUCCESSFUL_POLL_GAUGE: &str = "lekko_sidecar_last_successful_poll_timestamp_seconds";
+
+// Counter of new versions of the repository that were rejected because they are
+// invalid, labeled with the reason.
+pub const SNAPSHOT_REJECTED_COUNTER: &str = "lekko_sidecar_snapshot_rejected_counter";
+
+// Counter of restarts of the config source after it panicked.
+pub const SOURCE_RESTART_COUNTER: &str = "lekko_sidecar_source_restart_counter";
+
+// Counter of failed evaluations, labeled with the kind of error.
+pub const EVALUATION_ERROR_COUNTER: &str = "lekko_sidecar_evaluation_error_counter";
+
+// Counts a failed evaluation, and converts the error to the status returned to
+// the client. Type mismatches are caused by the client's context, everything else
+// by the config or the sidecar.
+pub fn evaluation_error(error: EvalError) -> Status {
+    counter!(EVALUATION_ERROR_COUNTER, 1, "kind" => error.kind());
+    let code = match error {
+        EvalError::TypeMismatch { .. } => Code::InvalidArgument,
+        _ => Code::Internal,
+    };
+    Status::new(code, error.to_string())
+}
@@ -77,6 +77,12 @@ impl RepoFS {
         Ok(fs)
     }
 
+    // Whether the repo is checked out by git-sync, which swaps the contents link to
+    // point at each new checkout.
+    pub fn is_git_sync(&self) -> bool {
+        Path::new(&self.contents_path).is_symlink()
+    }
+
     #[allow(clippy::question_mark)]
     pub fn load(&self) -> Result<GetRepositoryContentsResponse, Status> {
         let commit_sha = self.git_commit_sha()?;
@@ -100,32 +106,12 @@ impl RepoFS {
     // find namespaces contained in the repo by inspecting lekko.root.yaml.
     fn find_namespace_names(&self) -> Result<Vec<String>, Status> {
         let lekko_root_path = format!("{:}/lekko.root.yaml", self.contents_path.to_owned());
-        let yaml = match read_to_string(&lekko_root_path) {
-            Ok(contents) => match YamlLoader::load_from_str(contents.as_ref()) {
-                Ok(docs) => docs
-                    .first()
-                    .ok_or_else(|| Status::internal("invalid lekko.root.yaml"))?
-                    .to_owned(),
-                Err(e) => {
-                    return Err(Status::internal(format!(
-                        "failed to parse lekko yaml: {e:?}",
-                    )))
-                }
-            },
-            Err(e) => {
-                return Err(Status::internal(format!(
-                    "failed to read lekko yaml from {lekko_root_path:?}: {e:?}",
-                )))
-            }
-        };
-        yaml["namespaces"]
-            .clone()
-            .into_iter()
-            .map(|elem| match elem.as_str() {
-                Some(s) => Ok(s.to_owned()),
-                None => Err(Status::internal("unknown namespace")),
-            })
-            .collect()
+        match read_to_string(&lekko_root_path) {
+            Ok(contents) => parse_namespace_names(&contents),
+            Err(e) => Err(Status::internal(format!(
+                "failed to read lekko yaml from {lekko_root_path:?}: {e:?}",
+            ))),
+        }
     }
 
     fn load_namespace(&self, namespace: &str) -> Result<Namespace, Status> {
@@ -173,18 +159,7 @@ impl RepoFS {
                             }
                         };
                         let sha = self.git_hash_object(bytes.as_ref())?;
-                        features.push(Feature {
-                            name: String::from(feature_name),
-                            sha: sha.to_owned(),
-                            feature: match feature::v1beta1::Feature::decode(bytes.as_ref()) {
-                                Ok(d) => Some(d),
-                                Err(e) => {
-                                    return Err(Status::internal(format!(
-                                        "decode feature from git-sync: {e:?}",
-                                    )));
-                                }
-                            },
-                        });
+                        features.push(decode_feature(feature_name, &sha, &bytes)?);
                         debug!(
                             "initialized {feature_name:} [{:?} bytes]: sha {sha:}",
                             bytes.len()
@@ -270,17 +245,55 @@ impl RepoFS {
             Err(e) => Err(Status::internal(format!(
                 "failed to read proto image path: {e:?}"
             ))),
-            Ok(bytes) => {
-                let fds = match FileDescriptorSet::decode(bytes.as_ref()) {
-                    Err(e) => {
-                        return Err(Status::internal(format!(
-                            "failed to decode file descriptor set: {e:?}"
-                        )));
-                    }
-                    Ok(decoded) => decoded,
-                };
-                Ok(fds)
-            }
+            Ok(bytes) => decode_file_descriptor_set(&bytes),
         }
     }
 }
+
+// Parses the names of the namespaces in the repo out of lekko.root.yaml.
+pub(crate) fn parse_namespace_names(lekko_root: &str) -> Result<Vec<String>, Status> {
+    let yaml = match YamlLoader::load_from_str(lekko_root) {
+        Ok(docs) => docs
+            .first()
+            .ok_or_else(|| Status::internal("invalid lekko.root.yaml"))?
+            .to_owned(),
+        Err(e) => {
+            return Err(Status::internal(format!(
+                "failed to parse lekko yaml: {e:?}",
+            )))
+        }
+    };
+    yaml["namespaces"]
+        .clone()
+        .into_iter()
+        .map(|elem| match elem.as_str() {
+            Some(s) => Ok(s.to_owned()),
+            None => Err(Status::internal("unknown namespace")),
+        })
+        .collect()
+}
+
+// Decodes a compiled feature, <feature_name>.proto.bin, whose blob has the sha.
+pub(crate) fn decode_feature(
+    feature_name: &str,
+    sha: &str,
+    bytes: &[u8],
+) -> Result<Feature, Status> {
+    Ok(Feature {
+        name: String::from(feature_name),
+        sha: sha.to_owned(),
+        feature: match feature::v1beta1::Feature::decode(bytes) {
+            Ok(d) => Some(d),
+            Err(e) => {
+                return Err(Status::internal(format!(
+                    "decode feature {feature_name}: {e:?}",
+                )));
+            }
+        },
+    })
+}
+
+pub(crate) fn decode_file_descriptor_set(bytes: &[u8]) -> Result<FileDescriptorSet, Status> {
+    FileDescriptorSet::decode(bytes)
+        .map_err(|e| Status::internal(format!("failed to decode file descriptor set: {e:?}")))
+}
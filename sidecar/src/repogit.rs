@@ -0,0 +1,188 @@
+use std::path::{Path, PathBuf};
+
+use gix::{bstr::ByteSlice, object::Kind, objs::tree::EntryMode, ObjectId, ThreadSafeRepository};
+use log::{debug, warn};
+use prost_types::FileDescriptorSet;
+use tonic::Status;
+
+use crate::{
+    gen::cli::lekko::backend::v1beta1::{GetRepositoryContentsResponse, Namespace, RepositoryKey},
+    repofs::{decode_feature, decode_file_descriptor_set, parse_namespace_names},
+    types::get_owner_and_repo,
+};
+
+// Helps the sidecar load feature flags from the git object database of a config
+// repo, at a ref or commit, rather than from the working tree like RepoFS.
+//
+// Every load reads the tree of a single commit, so a dirty or half checked out
+// working tree is never served, and the feature shas are the ids of the blobs they
+// were read from. Bare repositories are supported, since nothing is read from the
+// working tree.
+pub struct RepoGit {
+    repo: ThreadSafeRepository,
+    // The ref or commit to load, e.g. HEAD or origin/main.
+    rev: String,
+}
+
+impl RepoGit {
+    // Fails if the repo can't be opened, or the rev isn't a commit in it.
+    pub fn new(repo_path: String, rev: String) -> Result<Self, Status> {
+        let repo = ThreadSafeRepository::open(&repo_path)
+            .map_err(|e| Status::internal(format!("failed to open repo at {repo_path}: {e:?}")))?;
+        let repo_git = Self { repo, rev };
+        repo_git.commit_sha()?;
+        Ok(repo_git)
+    }
+
+    // The directory that contains the refs and objects, which is the repo itself
+    // if it is bare.
+    pub fn git_dir(&self) -> PathBuf {
+        self.repo.git_dir().to_owned()
+    }
+
+    // Resolves the rev to the commit it currently points to.
+    pub fn commit_sha(&self) -> Result<String, Status> {
+        Ok(self.resolve(&self.repo.to_thread_local())?.to_string())
+    }
+
+    pub fn load(&self) -> Result<GetRepositoryContentsResponse, Status> {
+        let repo = self.repo.to_thread_local();
+        let commit_id = self.resolve(&repo)?;
+        let tree_id = find_object(&repo, commit_id)?
+            .peel_to_tree()
+            .map_err(|e| Status::internal(format!("failed to read commit {commit_id}: {e:?}")))?
+            .id;
+
+        let lekko_root = read_blob(&repo, tree_id, "lekko.root.yaml")?;
+        let lekko_root = lekko_root
+            .to_str()
+            .map_err(|e| Status::internal(format!("invalid lekko.root.yaml: {e:?}")))?;
+        let namespaces = parse_namespace_names(lekko_root)?
+            .iter()
+            .map(|ns| load_namespace(&repo, tree_id, ns))
+            .collect::<Result<Vec<Namespace>, Status>>()?;
+        let fds = self.file_descriptor_set(&repo, tree_id)?;
+        Ok(GetRepositoryContentsResponse {
+            commit_sha: commit_id.to_string(),
+            namespaces,
+            file_descriptor_set: Some(fds),
+        })
+    }
+
+    // Determines the repo key based on the default remote of the
+    // repository.
+    pub fn repo_key(&self) -> Result<RepositoryKey, Status> {
+        let repo = self.repo.to_thread_local();
+        let config = repo.config_snapshot();
+        let url_bytes = config
+            .string("remote.origin.url")
+            .ok_or_else(|| Status::internal("cannot find url in remote section"))?;
+        let url = url_bytes
+            .to_str()
+            .map_err(|e| Status::internal(format!("decode url string error: {}", e)))?;
+        let (owner_name, repo_name) = get_owner_and_repo(url)
+            .ok_or_else(|| Status::internal(format!("invalid remote url {url}")))?;
+        Ok(RepositoryKey {
+            owner_name,
+            repo_name,
+        })
+    }
+
+    fn resolve(&self, repo: &gix::Repository) -> Result<ObjectId, Status> {
+        let id = repo
+            .rev_parse_single(self.rev.as_str())
+            .map_err(|e| Status::internal(format!("failed rev parse {}: {e:?}", self.rev)))?;
+        // Tags are followed to the commit they point to.
+        let commit = id
+            .object()
+            .map_err(|e| Status::internal(format!("failed to read {}: {e:?}", self.rev)))?
+            .peel_to_kind(Kind::Commit)
+            .map_err(|e| Status::internal(format!("{} is not a commit: {e:?}", self.rev)))?;
+        Ok(commit.id)
+    }
+
+    // Reads the file descriptor set for types in the repository.
+    fn file_descriptor_set(
+        &self,
+        repo: &gix::Repository,
+        tree_id: ObjectId,
+    ) -> Result<FileDescriptorSet, Status> {
+        // Assumes default location for compiled protobuf image
+        let bytes = read_blob(repo, tree_id, "proto/image.bin")?;
+        decode_file_descriptor_set(&bytes)
+    }
+}
+
+fn load_namespace(
+    repo: &gix::Repository,
+    tree_id: ObjectId,
+    namespace: &str,
+) -> Result<Namespace, Status> {
+    let ns_path = format!("{namespace}/gen/proto");
+    let ns_tree = match lookup(repo, tree_id, &ns_path)? {
+        Some((mode, id)) if mode.is_tree() => find_object(repo, id)?.into_tree(),
+        _ => {
+            return Err(Status::invalid_argument(format!(
+                "error encountered reading dir: {ns_path} is not a directory",
+            )))
+        }
+    };
+
+    let mut features = vec![];
+    for entry in ns_tree.iter() {
+        let entry = entry
+            .map_err(|e| Status::invalid_argument(format!("failed to read dir content: {e:?}")))?;
+        if !entry.mode().is_blob() {
+            continue;
+        }
+        let filename = entry
+            .filename()
+            .to_str()
+            .map_err(|_| Status::internal("file name empty"))?;
+        let feature_name = match filename.strip_suffix(".proto.bin") {
+            Some(a) => a,
+            None => {
+                warn!("malformed filename in {ns_path}, skipping");
+                continue;
+            }
+        };
+        let sha = entry.oid();
+        let bytes = find_object(repo, sha)?.detach().data;
+        features.push(decode_feature(feature_name, &sha.to_string(), &bytes)?);
+        debug!(
+            "initialized {feature_name:} [{:?} bytes]: sha {sha:}",
+            bytes.len()
+        );
+    }
+    Ok(Namespace {
+        name: namespace.to_owned(),
+        features,
+    })
+}
+
+// Looks up the mode and id of the entry at the path in the tree, if there is one.
+fn lookup(
+    repo: &gix::Repository,
+    tree_id: ObjectId,
+    path: &str,
+) -> Result<Option<(EntryMode, ObjectId)>, Status> {
+    let entry = find_object(repo, tree_id)?
+        .into_tree()
+        .lookup_entry_by_path(Path::new(path))
+        .map_err(|e| Status::internal(format!("failed to look up {path}: {e:?}")))?;
+    Ok(entry.map(|entry| (entry.mode(), entry.object_id())))
+}
+
+fn read_blob(repo: &gix::Repository, tree_id: ObjectId, path: &str) -> Result<Vec<u8>, Status> {
+    match lookup(repo, tree_id, path)? {
+        Some((mode, id)) if mode.is_blob() => Ok(find_object(repo, id)?.detach().data),
+        _ => Err(Status::internal(format!(
+            "failed to read {path}: not a file in tree {tree_id}"
+        ))),
+    }
+}
+
+fn find_object(repo: &gix::Repository, id: ObjectId) -> Result<gix::Object<'_>, Status> {
+    repo.find_object(id)
+        .map_err(|e| Status::internal(format!("failed to read object {id}: {e:?}")))
+}
@@ -0,0 +1,141 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{
+    gen::cli::lekko::backend::v1beta1::RepositoryKey, metrics::Metrics, store::Store,
+    types::ConnectionCredentials,
+};
+
+// Repo is everything the sidecar keeps for one of the config repositories it
+// serves.
+pub struct Repo {
+    pub key: RepositoryKey,
+    pub store: Arc<Store>,
+    pub metrics: Option<Metrics>,
+    pub conn_creds: Option<ConnectionCredentials>,
+}
+
+// Repos routes requests to the repository they are for, by the repo key they
+// carry. Each repository has its own store, so they are loaded and updated
+// independently.
+#[derive(Default)]
+pub struct Repos {
+    repos: BTreeMap<(String, String), Arc<Repo>>,
+}
+
+impl Repos {
+    // Fails if two of the repositories have the same repo key.
+    pub fn new(repos: impl IntoIterator<Item = Repo>) -> Result<Self, tonic::Status> {
+        let mut by_key = BTreeMap::new();
+        for repo in repos {
+            let key = (repo.key.owner_name.clone(), repo.key.repo_name.clone());
+            if by_key.contains_key(&key) {
+                return Err(tonic::Status::invalid_argument(format!(
+                    "repository {}/{} is configured more than once",
+                    key.0, key.1
+                )));
+            }
+            by_key.insert(key, Arc::new(repo));
+        }
+        Ok(Self { repos: by_key })
+    }
+
+    // The repository with the given owner and name. Fails if this sidecar doesn't
+    // serve it.
+    pub fn get(&self, owner_name: &str, repo_name: &str) -> Result<&Arc<Repo>, tonic::Status> {
+        self.repos
+            .get(&(owner_name.to_owned(), repo_name.to_owned()))
+            .ok_or_else(|| {
+                tonic::Status::invalid_argument(format!(
+                    "registration mismatch: requested_repo: {owner_name}/{repo_name}, vs. repos: {}",
+                    self.names().join(", ")
+                ))
+            })
+    }
+
+    // The repository a request without a repo key is for, which is only known if
+    // this sidecar serves a single repository.
+    pub fn only(&self) -> Result<&Arc<Repo>, tonic::Status> {
+        match self.repos.len() {
+            1 => Ok(self.repos.values().next().unwrap()),
+            _ => Err(tonic::Status::invalid_argument("no repo key provided")),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<Repo>> {
+        self.repos.values()
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.repos
+            .keys()
+            .map(|(owner_name, repo_name)| format!("{owner_name}/{repo_name}"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        gen::cli::lekko::backend::v1beta1::GetRepositoryContentsResponse,
+        source::ConfigSource,
+        store::{NamespaceFilter, Updates},
+    };
+
+    struct EmptySource;
+
+    #[tonic::async_trait]
+    impl ConfigSource for EmptySource {
+        async fn load(
+            &self,
+            _namespaces: &NamespaceFilter,
+        ) -> Result<GetRepositoryContentsResponse, tonic::Status> {
+            Ok(GetRepositoryContentsResponse::default())
+        }
+
+        async fn watch(&mut self, _updates: &Updates) {}
+    }
+
+    async fn repo(owner_name: &str, repo_name: &str) -> Repo {
+        let store = Store::from_source(Box::new(EmptySource), NamespaceFilter::default(), 1)
+            .await
+            .unwrap();
+        Repo {
+            key: RepositoryKey {
+                owner_name: owner_name.to_owned(),
+                repo_name: repo_name.to_owned(),
+            },
+            store: Arc::new(store),
+            metrics: None,
+            conn_creds: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_route() {
+        let repos = Repos::new([
+            repo("lekkodev", "platform").await,
+            repo("lekkodev", "team").await,
+        ])
+        .unwrap();
+        let team = repos.get("lekkodev", "team").unwrap();
+        assert_eq!(team.key.repo_name, "team");
+        let err = repos.get("lekkodev", "other").err().unwrap();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        assert_eq!(
+            err.message(),
+            "registration mismatch: requested_repo: lekkodev/other, vs. repos: lekkodev/platform, lekkodev/team"
+        );
+        // requests without a repo key are ambiguous with several repos
+        assert!(repos.only().is_err());
+
+        let single = Repos::new([repo("lekkodev", "platform").await]).unwrap();
+        assert_eq!(single.only().unwrap().key.repo_name, "platform");
+
+        let duplicate = Repos::new([
+            repo("lekkodev", "platform").await,
+            repo("lekkodev", "platform").await,
+        ]);
+        assert!(duplicate.is_err());
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use prost::Message;
+use sha1::{Digest, Sha1};
+
+use crate::gen::cli::lekko::backend::v1beta1::{GetRepositoryContentsResponse, RepositoryKey};
+
+// Identifies the format of snapshot files, in case it ever needs to change.
+const MAGIC: &[u8; 8] = b"LKSNAP01";
+const CHECKSUM_LEN: usize = 20;
+
+// SnapshotCache persists the last repository contents that were successfully loaded
+// from Lekko, so that the sidecar can start from them if Lekko is unreachable.
+//
+// The snapshot is a single file per repository, made up of a header, the sha1 of the
+// encoded contents, and the encoded GetRepositoryContentsResponse. It is replaced
+// atomically, so a crash while writing leaves the previous snapshot in place, and
+// the checksum catches any other corruption.
+#[derive(Clone, Debug)]
+pub struct SnapshotCache {
+    path: PathBuf,
+}
+
+impl SnapshotCache {
+    pub fn new(dir: impl Into<PathBuf>, repo_key: &RepositoryKey) -> Self {
+        Self {
+            path: dir.into().join(format!(
+                "{}.{}.snapshot",
+                repo_key.owner_name, repo_key.repo_name
+            )),
+        }
+    }
+
+    // A snapshot at the given path, rather than in a cache directory.
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn write(&self, contents: &GetRepositoryContentsResponse) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let encoded = contents.encode_to_vec();
+        // Write to a temporary file in the same directory, so that it can be renamed
+        // over the previous snapshot.
+        let tmp_path = self.path.with_extension("snapshot.tmp");
+        let mut file = File::create(&tmp_path)?;
+        file.write_all(MAGIC)?;
+        file.write_all(&Sha1::digest(&encoded))?;
+        file.write_all(&encoded)?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, &self.path)
+    }
+
+    // Reads the snapshot, failing with NotFound if there isn't one, or InvalidData if
+    // it is corrupted.
+    pub fn read(&self) -> io::Result<GetRepositoryContentsResponse> {
+        let data = fs::read(&self.path)?;
+        let invalid = |msg: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {msg}", self.path.display()),
+            )
+        };
+        let rest = data
+            .strip_prefix(MAGIC.as_slice())
+            .ok_or_else(|| invalid("not a snapshot file"))?;
+        if rest.len() < CHECKSUM_LEN {
+            return Err(invalid("truncated snapshot"));
+        }
+        let (checksum, encoded) = rest.split_at(CHECKSUM_LEN);
+        if Sha1::digest(encoded).as_slice() != checksum {
+            return Err(invalid("checksum mismatch"));
+        }
+        GetRepositoryContentsResponse::decode(encoded).map_err(|e| invalid(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::cli::lekko::backend::v1beta1::Namespace;
+
+    fn cache(name: &str) -> SnapshotCache {
+        let dir = std::env::temp_dir().join(format!(
+            "sidecar-snapshot-cache-{}-{name}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        SnapshotCache::new(
+            dir,
+            &RepositoryKey {
+                owner_name: String::from("lekkodev"),
+                repo_name: String::from("example"),
+            },
+        )
+    }
+
+    fn contents(commit_sha: &str) -> GetRepositoryContentsResponse {
+        GetRepositoryContentsResponse {
+            commit_sha: commit_sha.to_owned(),
+            namespaces: vec![Namespace {
+                name: String::from("ns"),
+                features: vec![],
+            }],
+            file_descriptor_set: None,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let cache = cache("round_trip");
+        assert_eq!(cache.read().unwrap_err().kind(), io::ErrorKind::NotFound);
+        cache.write(&contents("abc")).unwrap();
+        assert_eq!(cache.read().unwrap(), contents("abc"));
+        // later writes replace the snapshot, without leaving anything behind
+        cache.write(&contents("def")).unwrap();
+        assert_eq!(cache.read().unwrap(), contents("def"));
+        let files = fs::read_dir(cache.path.parent().unwrap()).unwrap().count();
+        assert_eq!(files, 1);
+    }
+
+    #[test]
+    fn test_corrupted() {
+        let cache = cache("corrupted");
+        cache.write(&contents("abc")).unwrap();
+        let data = fs::read(&cache.path).unwrap();
+
+        let mut flipped = data.clone();
+        *flipped.last_mut().unwrap() ^= 1;
+        fs::write(&cache.path, flipped).unwrap();
+        let err = cache.read().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().ends_with("checksum mismatch"), "{err}");
+
+        fs::write(&cache.path, &data[..MAGIC.len() + 4]).unwrap();
+        assert_eq!(cache.read().unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        fs::write(&cache.path, b"something else").unwrap();
+        assert_eq!(cache.read().unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,422 @@
+use std::time::Duration;
+
+use hyper::client::HttpConnector;
+use hyper_rustls::HttpsConnector;
+use log::{error, info, warn};
+use tokio::time::sleep;
+use tonic::{body::BoxBody, Request};
+
+use super::ConfigSource;
+use crate::{
+    backoff::{jitter, Backoff},
+    gen::cli::lekko::backend::v1beta1::{
+        distribution_service_client::DistributionServiceClient, GetRepositoryContentsRequest,
+        GetRepositoryContentsResponse, GetRepositoryVersionRequest, RegisterClientRequest,
+    },
+    snapshot_cache::SnapshotCache,
+    store::{NamespaceFilter, Updates},
+    types::{add_api_key, ConnectionCredentials, APIKEY},
+};
+
+// The longest the poll loop waits between retries after repeated failures.
+const MAX_POLL_BACKOFF: Duration = Duration::from_secs(300);
+
+type DistClient = DistributionServiceClient<hyper::Client<HttpsConnector<HttpConnector>, BoxBody>>;
+
+// BackendSource loads a config repo from Lekko, and polls it for new versions. This
+// is what default mode uses.
+//
+// Every version that the store accepts is persisted to the snapshot cache, if there
+// is one, so that the sidecar can start from it if Lekko is unreachable.
+pub struct BackendSource {
+    dist_client: DistClient,
+    conn_creds: ConnectionCredentials,
+    poll_interval: Duration,
+    snapshot_cache: Option<SnapshotCache>,
+    // The registration to retry before polling, if the sidecar couldn't register at
+    // startup.
+    registration: Option<RegisterClientRequest>,
+}
+
+impl BackendSource {
+    pub fn new(
+        dist_client: DistClient,
+        conn_creds: ConnectionCredentials,
+        poll_interval: Duration,
+        snapshot_cache: Option<SnapshotCache>,
+    ) -> Self {
+        Self {
+            dist_client,
+            conn_creds,
+            poll_interval,
+            snapshot_cache,
+            registration: None,
+        }
+    }
+
+    // Registers with the given request before polling, for a sidecar that started
+    // from a cached snapshot because it couldn't reach Lekko to register. Polls fail
+    // until the registration succeeds, so it is retried with the same backoff.
+    pub fn register_before_polling(&mut self, request: RegisterClientRequest) {
+        self.registration = Some(request);
+    }
+
+    // Sets the session to poll with, once the sidecar has registered. The initial
+    // load doesn't need one. The key is shared with every clone of the connection
+    // credentials, so metrics are sent with it too.
+    fn set_session_key(&self, session_key: String) {
+        self.conn_creds.session_key.set(session_key);
+    }
+
+    // Registers the sidecar if it hasn't registered yet, and polls with the session
+    // it gets.
+    async fn register(&mut self) -> Result<(), tonic::Status> {
+        let Some(request) = self.registration.clone() else {
+            return Ok(());
+        };
+        let session_key = self
+            .dist_client
+            .register_client(add_api_key(request, self.conn_creds.api_key.clone()))
+            .await?
+            .into_inner()
+            .session_key;
+        info!("registered with lekko");
+        self.set_session_key(session_key);
+        self.registration = None;
+        Ok(())
+    }
+
+    // Fetches and loads the latest version of the repository, if it changed. On
+    // failure, returns the stage that failed, for metrics.
+    async fn poll(&mut self, updates: &Updates) -> Result<(), &'static str> {
+        if let Err(err) = self.register().await {
+            error!("got an error when registering {err:?}");
+            return Err("register");
+        }
+        // fetch version
+        let new_version = match get_repo_version_remote(
+            self.dist_client.clone(),
+            self.conn_creds.clone(),
+        )
+        .await
+        {
+            Ok(v) => v,
+            Err(err) => {
+                error!("got an error when fetching version {err:?}");
+                return Err("version");
+            }
+        };
+        // A cached snapshot is loaded again from Lekko even if it has the latest
+        // version, so that the store stops being degraded.
+        if updates.latest_version() == new_version && !updates.is_degraded() {
+            return Ok(());
+        }
+
+        info!("found new version: {new_version}, fetching");
+
+        match self.load(&updates.namespaces()).await {
+            Ok(res) => match updates.load(&res) {
+                Ok(()) => {
+                    self.write_snapshot(&res);
+                    Ok(())
+                }
+                Err(err) => {
+                    // Keep serving the current version. Since the version isn't updated, the
+                    // new version will be fetched again on the next poll.
+                    error!(
+                        "failed to load repo contents for commit sha {:}: {err:?}",
+                        res.commit_sha
+                    );
+                    Err("load")
+                }
+            },
+            Err(err) => {
+                // This is a problem, error loudly.
+                error!("error encountered when fetching full repository state: {err:?}",);
+                Err("contents")
+            }
+        }
+    }
+
+    // Persists contents that were just loaded. Failing to do so doesn't affect the
+    // running sidecar, only how it can start up, so it is only logged.
+    fn write_snapshot(&self, contents: &GetRepositoryContentsResponse) {
+        if let Some(snapshot_cache) = &self.snapshot_cache {
+            if let Err(e) = snapshot_cache.write(contents) {
+                warn!(
+                    "failed to persist snapshot for commit sha {}: {e:}",
+                    contents.commit_sha
+                );
+            }
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ConfigSource for BackendSource {
+    async fn load(
+        &self,
+        namespaces: &NamespaceFilter,
+    ) -> Result<GetRepositoryContentsResponse, tonic::Status> {
+        get_repo_contents_remote(self.dist_client.clone(), &self.conn_creds, namespaces).await
+    }
+
+    // Polls Lekko for new versions of the repository. Failed polls are retried with
+    // exponential backoff, so that sidecars don't all retry in lockstep while Lekko is
+    // having trouble.
+    async fn watch(&mut self, updates: &Updates) {
+        // The store was started from a version that it accepted.
+        self.write_snapshot(&updates.latest_contents());
+        let mut backoff = Backoff::new(self.poll_interval, MAX_POLL_BACKOFF);
+        // Start at a random point in the first interval, so that sidecars that start
+        // at the same time don't poll at the same time.
+        sleep(jitter(self.poll_interval)).await;
+        loop {
+            let wait = match self.poll(updates).await {
+                Ok(()) => {
+                    backoff.reset();
+                    updates.record_success();
+                    self.poll_interval
+                }
+                Err(stage) => {
+                    let wait = backoff.fail();
+                    updates.record_failure(stage, backoff.failures());
+                    warn!(
+                        "poll failed {} times in a row, retrying in {wait:?}",
+                        backoff.failures()
+                    );
+                    wait
+                }
+            };
+            sleep(wait).await;
+        }
+    }
+}
+
+fn conn_creds_to_repo_contents_request(
+    conn_creds: ConnectionCredentials,
+    namespace_name: String,
+) -> Request<GetRepositoryContentsRequest> {
+    let mut req = Request::new(GetRepositoryContentsRequest {
+        repo_key: Some(conn_creds.repo_key),
+        session_key: conn_creds.session_key.get(),
+        namespace_name,
+        feature_name: "".to_string(),
+    });
+    req.metadata_mut().append(APIKEY, conn_creds.api_key);
+    req
+}
+
+async fn get_repo_version_remote(
+    mut dist_client: DistClient,
+    conn_creds: ConnectionCredentials,
+) -> Result<String, tonic::Status> {
+    dist_client
+        .get_repository_version(add_api_key(
+            GetRepositoryVersionRequest {
+                repo_key: Some(conn_creds.repo_key),
+                session_key: conn_creds.session_key.get(),
+            },
+            conn_creds.api_key,
+        ))
+        .await
+        .map(|resp| resp.into_inner().commit_sha)
+}
+
+// Fetches the contents of the namespaces included by the filter. Each namespace is
+// fetched separately, and they must all come from the same commit.
+pub async fn get_repo_contents_remote(
+    mut dist_client: DistClient,
+    conn_creds: &ConnectionCredentials,
+    filter: &NamespaceFilter,
+) -> Result<GetRepositoryContentsResponse, tonic::Status> {
+    let Some(namespaces) = filter.namespaces() else {
+        return dist_client
+            .get_repository_contents(conn_creds_to_repo_contents_request(
+                conn_creds.clone(),
+                "".to_string(),
+            ))
+            .await
+            .map(|resp| resp.into_inner());
+    };
+    let mut contents: Option<GetRepositoryContentsResponse> = None;
+    for namespace in namespaces {
+        let res = dist_client
+            .get_repository_contents(conn_creds_to_repo_contents_request(
+                conn_creds.clone(),
+                namespace,
+            ))
+            .await?
+            .into_inner();
+        match contents.as_mut() {
+            None => contents = Some(res),
+            Some(contents) if contents.commit_sha == res.commit_sha => {
+                contents.namespaces.extend(res.namespaces)
+            }
+            Some(contents) => {
+                return Err(tonic::Status::aborted(format!(
+                    "repository changed from commit sha {} to {} while fetching namespaces",
+                    contents.commit_sha, res.commit_sha
+                )))
+            }
+        }
+    }
+    Ok(contents.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    use hyper_rustls::HttpsConnectorBuilder;
+    use tokio::net::TcpListener;
+    use tonic::{transport::Server, Response, Status};
+
+    use super::*;
+    use crate::{
+        gen::cli::lekko::backend::v1beta1::{
+            distribution_service_server::{DistributionService, DistributionServiceServer},
+            DeregisterClientRequest, DeregisterClientResponse, GetDeveloperAccessTokenRequest,
+            GetDeveloperAccessTokenResponse, GetRepositoryVersionResponse, RegisterClientResponse,
+            RepositoryKey, SendFlagEvaluationMetricsRequest, SendFlagEvaluationMetricsResponse,
+        },
+        store::Store,
+        types::SessionKey,
+    };
+
+    // A backend that is unreachable for the first few registrations, and only serves
+    // new versions to registered sidecars.
+    struct FakeBackend {
+        unreachable_registrations: u32,
+        registrations: Arc<AtomicU32>,
+    }
+
+    impl FakeBackend {
+        fn check_session(session_key: &str) -> Result<(), Status> {
+            if session_key != "session" {
+                return Err(Status::unauthenticated("not registered"));
+            }
+            Ok(())
+        }
+    }
+
+    #[tonic::async_trait]
+    impl DistributionService for FakeBackend {
+        async fn get_repository_version(
+            &self,
+            request: tonic::Request<GetRepositoryVersionRequest>,
+        ) -> Result<Response<GetRepositoryVersionResponse>, Status> {
+            Self::check_session(&request.get_ref().session_key)?;
+            Ok(Response::new(GetRepositoryVersionResponse {
+                commit_sha: "v2".to_string(),
+            }))
+        }
+
+        async fn get_repository_contents(
+            &self,
+            request: tonic::Request<GetRepositoryContentsRequest>,
+        ) -> Result<Response<GetRepositoryContentsResponse>, Status> {
+            Self::check_session(&request.get_ref().session_key)?;
+            Ok(Response::new(GetRepositoryContentsResponse {
+                commit_sha: "v2".to_string(),
+                ..Default::default()
+            }))
+        }
+
+        async fn send_flag_evaluation_metrics(
+            &self,
+            _request: tonic::Request<SendFlagEvaluationMetricsRequest>,
+        ) -> Result<Response<SendFlagEvaluationMetricsResponse>, Status> {
+            Err(Status::unimplemented("send_flag_evaluation_metrics"))
+        }
+
+        async fn register_client(
+            &self,
+            _request: tonic::Request<RegisterClientRequest>,
+        ) -> Result<Response<RegisterClientResponse>, Status> {
+            if self.registrations.fetch_add(1, Ordering::SeqCst) < self.unreachable_registrations {
+                return Err(Status::unavailable("lekko is down"));
+            }
+            Ok(Response::new(RegisterClientResponse {
+                session_key: "session".to_string(),
+            }))
+        }
+
+        async fn deregister_client(
+            &self,
+            _request: tonic::Request<DeregisterClientRequest>,
+        ) -> Result<Response<DeregisterClientResponse>, Status> {
+            Err(Status::unimplemented("deregister_client"))
+        }
+
+        async fn get_developer_access_token(
+            &self,
+            _request: tonic::Request<GetDeveloperAccessTokenRequest>,
+        ) -> Result<Response<GetDeveloperAccessTokenResponse>, Status> {
+            Err(Status::unimplemented("get_developer_access_token"))
+        }
+    }
+
+    // Serves the backend on a local port, and returns a client for it.
+    async fn serve(backend: FakeBackend) -> DistClient {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let incoming = Box::pin(futures::stream::unfold(listener, |listener| async move {
+            let conn = listener.accept().await.map(|(stream, _)| stream);
+            Some((conn, listener))
+        }));
+        tokio::spawn(
+            Server::builder()
+                .add_service(DistributionServiceServer::new(backend))
+                .serve_with_incoming(incoming),
+        );
+        let connector = HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_or_http()
+            .enable_http2()
+            .build();
+        DistributionServiceClient::with_origin(
+            hyper::Client::builder().http2_only(true).build(connector),
+            format!("http://{addr}").parse().unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_deferred_registration() {
+        let registrations = Arc::new(AtomicU32::default());
+        let dist_client = serve(FakeBackend {
+            unreachable_registrations: 2,
+            registrations: registrations.clone(),
+        })
+        .await;
+        // started from a cached snapshot, without a session
+        let conn_creds = ConnectionCredentials {
+            repo_key: RepositoryKey::default(),
+            api_key: "key".parse().unwrap(),
+            session_key: SessionKey::default(),
+        };
+        let mut source = BackendSource::new(
+            dist_client,
+            conn_creds.clone(),
+            Duration::from_millis(1),
+            None,
+        );
+        source.register_before_polling(RegisterClientRequest::default());
+        let cached = GetRepositoryContentsResponse {
+            commit_sha: "v1".to_string(),
+            ..Default::default()
+        };
+        let store = Store::new(cached, Box::new(source), NamespaceFilter::default(), 1).unwrap();
+        store.mark_from_cache();
+
+        let mut changes = store.subscribe();
+        assert_eq!(changes.recv().await.unwrap().new_commit_sha, "v2");
+        assert_eq!(registrations.load(Ordering::SeqCst), 3);
+        // the session is shared with everything that holds the credentials, such
+        // as metrics
+        assert_eq!(conn_creds.session_key.get(), "session");
+    }
+}
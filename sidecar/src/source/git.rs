@@ -0,0 +1,175 @@
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+};
+
+use lazy_static::lazy_static;
+use log::warn;
+use notify::RecursiveMode;
+use regex::Regex;
+use tokio::sync::mpsc;
+
+use super::{next_change, watch_paths, ConfigSource, PathWatcher};
+use crate::{
+    gen::cli::lekko::backend::v1beta1::GetRepositoryContentsResponse,
+    repofs::RepoFS,
+    repogit::RepoGit,
+    store::{NamespaceFilter, Updates},
+    types::WatchMode,
+};
+
+lazy_static! {
+    // Lazily initialize static regex so we don't need to compile it repeatedly
+    // Matches the compiled features, and the image of the repository's proto types.
+    static ref PROTO_BIN_FILE: Regex = match Regex::new(r"^.*/(gen/proto/[\w.-]+\.proto\.bin|proto/image\.bin)$") {
+        Ok(r) => r,
+        Err(e) => panic!("failed to initialize regex {e:}"),
+    };
+}
+
+// The link that git-sync atomically swaps to point at each new checkout.
+const GIT_SYNC_LINK: &str = "contents";
+// The files in the git dir, besides the refs directory, that can move a ref.
+const GIT_REF_FILES: [&str; 2] = ["HEAD", "packed-refs"];
+
+// GitSource loads a config repo from a git working tree on disk, and reloads it
+// whenever it changes. This is what static mode uses.
+//
+// With native events, a git-sync layout is only reloaded when git-sync swaps the
+// contents link to a new checkout, rather than for each file it writes. Otherwise
+// any change to a compiled feature or the proto image causes a reload.
+//
+// At a rev, the repository is loaded from the git object database instead, and
+// reloaded whenever the rev moves to another commit.
+pub struct GitSource {
+    repo_path: String,
+    // The ref or commit to load, if not the working tree.
+    rev: Option<String>,
+    // Dropping the watcher stops reloads.
+    _watcher: PathWatcher,
+    changes: mpsc::Receiver<()>,
+}
+
+impl GitSource {
+    // The watch starts when the source is created, so that no change after the
+    // repository is first loaded is missed.
+    pub fn new(repo_path: String, watch_mode: WatchMode) -> Result<Self, tonic::Status> {
+        let repo = RepoFS::new(repo_path.clone())?;
+        let git_sync = watch_mode == WatchMode::Native && repo.is_git_sync();
+        // The link is replaced rather than modified, so its directory is watched.
+        let recursive_mode = match git_sync {
+            true => RecursiveMode::NonRecursive,
+            false => RecursiveMode::Recursive,
+        };
+        let (watcher, changes) = watch_paths(
+            &[(Path::new(&repo_path), recursive_mode)],
+            watch_mode,
+            move |path| match git_sync {
+                true => path.file_name() == Some(OsStr::new(GIT_SYNC_LINK)),
+                false => match path.to_str() {
+                    Some(st) => PROTO_BIN_FILE.is_match(st),
+                    None => {
+                        warn!("failed to convert path {path:?} to string");
+                        false // don't reload contents for paths that aren't unicode
+                    }
+                },
+            },
+        )?;
+        Ok(Self {
+            repo_path,
+            rev: None,
+            _watcher: watcher,
+            changes,
+        })
+    }
+
+    // Loads the repository at the rev, e.g. HEAD or origin/main, out of the git
+    // object database rather than the working tree. The repository may be bare.
+    pub fn at_rev(
+        repo_path: String,
+        rev: String,
+        watch_mode: WatchMode,
+    ) -> Result<Self, tonic::Status> {
+        let git_dir = RepoGit::new(repo_path.clone(), rev.clone())?.git_dir();
+        let refs_dir = git_dir.join("refs");
+        let ref_files: Vec<PathBuf> = GIT_REF_FILES.iter().map(|f| git_dir.join(f)).collect();
+        let relevant_refs_dir = refs_dir.clone();
+        // Refs are moved by renaming a lock file over them, so their directories are
+        // watched. The objects aren't, they are written before any ref points to them.
+        let (watcher, changes) = watch_paths(
+            &[
+                (&git_dir, RecursiveMode::NonRecursive),
+                (&refs_dir, RecursiveMode::Recursive),
+            ],
+            watch_mode,
+            move |path| path.starts_with(&relevant_refs_dir) || ref_files.iter().any(|f| path == f),
+        )?;
+        Ok(Self {
+            repo_path,
+            rev: Some(rev),
+            _watcher: watcher,
+            changes,
+        })
+    }
+
+    // Whether the rev still points to the commit that was loaded last. A commit
+    // always has the same contents, so it doesn't need to be loaded again when
+    // other refs move.
+    fn unchanged(&self, updates: &Updates) -> bool {
+        let Some(rev) = &self.rev else {
+            return false;
+        };
+        matches!(
+            RepoGit::new(self.repo_path.clone(), rev.clone()).and_then(|repo| repo.commit_sha()),
+            Ok(commit_sha) if commit_sha == updates.latest_version()
+        )
+    }
+}
+
+#[tonic::async_trait]
+impl ConfigSource for GitSource {
+    // The whole repository is loaded, the store drops the namespaces it doesn't keep.
+    async fn load(
+        &self,
+        _namespaces: &NamespaceFilter,
+    ) -> Result<GetRepositoryContentsResponse, tonic::Status> {
+        match &self.rev {
+            Some(rev) => RepoGit::new(self.repo_path.clone(), rev.clone())?.load(),
+            None => RepoFS::new(self.repo_path.clone())?.load(),
+        }
+    }
+
+    async fn watch(&mut self, updates: &Updates) {
+        while next_change(&mut self.changes).await {
+            if self.unchanged(updates) {
+                continue;
+            }
+            match self.load(&updates.namespaces()).await {
+                // The features, commit sha and file descriptor set are replaced together,
+                // so that readers never see features with the types of another version.
+                Ok(res) => {
+                    if let Err(e) = updates.load(&res) {
+                        warn!(
+                            "failed to load repo contents for commit sha {:}: {e:}",
+                            res.commit_sha
+                        );
+                    }
+                }
+                Err(e) => warn!("failed to load repo contents from filesystem: {e:}"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proto_bin_file() {
+        assert!(PROTO_BIN_FILE.is_match("/repo/contents/ns/gen/proto/flag.proto.bin"));
+        assert!(PROTO_BIN_FILE.is_match("/repo/contents/proto/image.bin"));
+        assert!(!PROTO_BIN_FILE.is_match("/repo/contents/ns/flag.star"));
+        assert!(!PROTO_BIN_FILE.is_match("/repo/contents/proto/example/v1/example.proto"));
+    }
+}
@@ -0,0 +1,121 @@
+use std::{path::Path, time::Duration};
+
+use log::error;
+use notify::{
+    Error, Event,
+    EventKind::{Create, Modify, Remove},
+    PollWatcher, RecommendedWatcher, RecursiveMode, Watcher,
+};
+use tokio::{
+    sync::mpsc,
+    time::{timeout, Instant},
+};
+
+use crate::{
+    gen::cli::lekko::backend::v1beta1::GetRepositoryContentsResponse,
+    store::{NamespaceFilter, Updates},
+    types::WatchMode,
+};
+
+pub mod backend;
+pub mod git;
+pub mod snapshot;
+
+pub use backend::BackendSource;
+pub use git::GitSource;
+pub use snapshot::SnapshotFileSource;
+
+// ConfigSource is where a store gets the contents of the repository from. The
+// store is started from an initial load, and then follows the versions the source
+// loads into it while watching.
+//
+// Sources only fetch and report versions. Compiling, validating, retaining and
+// publishing them is up to the store, so a source that loads an invalid version
+// only causes it to be rejected.
+#[tonic::async_trait]
+pub trait ConfigSource: Send + Sync + 'static {
+    // Loads the current version of the repository. Sources that can fetch only some
+    // namespaces should only fetch the ones the filter includes, the store drops
+    // the rest either way.
+    async fn load(
+        &self,
+        namespaces: &NamespaceFilter,
+    ) -> Result<GetRepositoryContentsResponse, tonic::Status>;
+
+    // Loads each new version of the repository into the store, for as long as the
+    // store exists. The task running it is aborted when the store is dropped.
+    //
+    // If watching panics, the store calls it again after a backoff, so it should
+    // pick up where it left off rather than assume it is only called once.
+    async fn watch(&mut self, updates: &Updates);
+}
+
+// How long a watched path has to be quiet after a change before it is reloaded, so
+// that a burst of changes, e.g. from a checkout, causes a single reload.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(250);
+// The longest a reload is delayed by a burst of changes.
+const WATCH_MAX_DEBOUNCE: Duration = Duration::from_secs(2);
+
+type PathWatcher = Box<dyn Watcher + Send + Sync>;
+
+// Starts watching the paths for changes, and returns a channel that receives a
+// message after changes to paths that are relevant. The channel is closed when the
+// watcher is dropped.
+fn watch_paths(
+    paths: &[(&Path, RecursiveMode)],
+    watch_mode: WatchMode,
+    relevant: impl Fn(&Path) -> bool + Send + 'static,
+) -> Result<(PathWatcher, mpsc::Receiver<()>), tonic::Status> {
+    // A pending reload covers any number of changes, so one slot is enough.
+    let (tx, rx) = mpsc::channel(1);
+    let handler = move |res: Result<Event, Error>| match res {
+        Ok(event) => {
+            if matches!(event.kind, Create(_) | Modify(_) | Remove(_))
+                && event.paths.iter().any(|path| relevant(path))
+            {
+                // Fails if a reload is already pending, or the watch stopped.
+                let _ = tx.try_send(());
+            }
+        }
+        Err(e) => error!("fs watch error: {:?}", e),
+    };
+    let watch_err = |e: notify::Error| {
+        let paths: Vec<_> = paths.iter().map(|(path, _)| path.display()).collect();
+        tonic::Status::internal(format!("error watching {paths:?}: {e}"))
+    };
+    let mut watcher: PathWatcher = match watch_mode {
+        WatchMode::Native => Box::new(
+            RecommendedWatcher::new(handler, notify::Config::default()).map_err(watch_err)?,
+        ),
+        WatchMode::Poll => Box::new(
+            PollWatcher::new(
+                handler,
+                notify::Config::default()
+                    .with_compare_contents(false)
+                    .with_poll_interval(Duration::from_secs(1)),
+            )
+            .map_err(watch_err)?,
+        ),
+    };
+    for (path, recursive_mode) in paths {
+        watcher.watch(path, *recursive_mode).map_err(watch_err)?;
+    }
+    Ok((watcher, rx))
+}
+
+// Waits for the next burst of changes to end. Returns false once the watcher is
+// dropped.
+async fn next_change(changes: &mut mpsc::Receiver<()>) -> bool {
+    if changes.recv().await.is_none() {
+        return false;
+    }
+    let deadline = Instant::now() + WATCH_MAX_DEBOUNCE;
+    while Instant::now() < deadline {
+        match timeout(WATCH_DEBOUNCE, changes.recv()).await {
+            Ok(Some(())) => continue,
+            Ok(None) => return false,
+            Err(_) => break,
+        }
+    }
+    true
+}
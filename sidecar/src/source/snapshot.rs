@@ -0,0 +1,84 @@
+use std::path::{Path, PathBuf};
+
+use log::warn;
+use notify::RecursiveMode;
+use tokio::sync::mpsc;
+
+use super::{next_change, watch_paths, ConfigSource, PathWatcher};
+use crate::{
+    gen::cli::lekko::backend::v1beta1::GetRepositoryContentsResponse,
+    snapshot_cache::SnapshotCache,
+    store::{NamespaceFilter, Updates},
+    types::WatchMode,
+};
+
+// SnapshotFileSource loads a config repo from a snapshot file, in the format the
+// snapshot cache writes, and reloads it whenever the file is replaced. This lets a
+// snapshot be built elsewhere, e.g. by a CI job, and shipped to the sidecar.
+pub struct SnapshotFileSource {
+    snapshot: SnapshotCache,
+    // Dropping the watcher stops reloads.
+    _watcher: PathWatcher,
+    changes: mpsc::Receiver<()>,
+}
+
+impl SnapshotFileSource {
+    pub fn new(path: impl Into<PathBuf>, watch_mode: WatchMode) -> Result<Self, tonic::Status> {
+        let snapshot = SnapshotCache::at(path);
+        let (Some(dir), Some(file_name)) = (snapshot.path().parent(), snapshot.path().file_name())
+        else {
+            return Err(tonic::Status::invalid_argument(format!(
+                "invalid snapshot path {}",
+                snapshot.path().display()
+            )));
+        };
+        let file_name = file_name.to_owned();
+        // Snapshots are replaced by renaming a new file over them, so the directory is
+        // watched rather than the file.
+        let dir = match dir.as_os_str().is_empty() {
+            true => Path::new("."),
+            false => dir,
+        };
+        let (watcher, changes) = watch_paths(
+            &[(dir, RecursiveMode::NonRecursive)],
+            watch_mode,
+            move |path| path.file_name() == Some(&file_name),
+        )?;
+        Ok(Self {
+            snapshot,
+            _watcher: watcher,
+            changes,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl ConfigSource for SnapshotFileSource {
+    // Snapshots contain whichever namespaces they were written with, the store drops
+    // the ones it doesn't keep.
+    async fn load(
+        &self,
+        _namespaces: &NamespaceFilter,
+    ) -> Result<GetRepositoryContentsResponse, tonic::Status> {
+        self.snapshot
+            .read()
+            .map_err(|e| tonic::Status::failed_precondition(format!("error reading snapshot: {e}")))
+    }
+
+    async fn watch(&mut self, updates: &Updates) {
+        while next_change(&mut self.changes).await {
+            match self.load(&updates.namespaces()).await {
+                Ok(res) => {
+                    if let Err(e) = updates.load(&res) {
+                        warn!(
+                            "failed to load snapshot for commit sha {:}: {e:}",
+                            res.commit_sha
+                        );
+                    }
+                }
+                // The snapshot may have been removed, keep serving the last one loaded.
+                Err(e) => warn!("failed to load snapshot: {e:}"),
+            }
+        }
+    }
+}
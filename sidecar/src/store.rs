@@ -1,339 +1,803 @@
 use std::{
-    collections::HashMap,
-    path::Path,
-    sync::{Arc, RwLock},
-    time::Duration,
+    any::Any,
+    collections::{BTreeSet, HashMap, VecDeque},
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    time::{Duration, SystemTime},
 };
 
 use crate::{
-    gen::cli::lekko::{
-        backend::{
-            self,
-            v1beta1::{
-                distribution_service_client::DistributionServiceClient,
-                GetRepositoryContentsRequest, GetRepositoryContentsResponse,
-                GetRepositoryVersionRequest, Namespace,
-            },
-        },
-        feature::v1beta1::Feature,
+    backoff::Backoff,
+    evaluate::{
+        evaluator::FeatureResolver,
+        plan::{CompiledFeature, Interner},
+    },
+    gen::cli::lekko::backend::{
+        self,
+        v1beta1::{GetRepositoryContentsResponse, Namespace},
     },
-    repofs::RepoFS,
-    types::{add_api_key, ConnectionCredentials, FeatureRequestParams, Mode, APIKEY},
+    metrics::{
+        DEGRADED_GAUGE, LAST_SUCCESSFUL_POLL_GAUGE, PINNED_GAUGE, POLL_CONSECUTIVE_FAILURES_GAUGE,
+        POLL_FAILURE_COUNTER, SNAPSHOT_REJECTED_COUNTER, SOURCE_RESTART_COUNTER,
+    },
+    source::ConfigSource,
+    types::FeatureRequestParams,
+    validation::{Problem, ValidationError, Validator},
 };
-use hyper::client::HttpConnector;
-use hyper_rustls::HttpsConnector;
-use itertools::any;
-use lazy_static::lazy_static;
+use arc_swap::{ArcSwap, Guard};
+use futures::FutureExt;
 use log::{error, info, warn};
-use notify::{
-    Error, Event,
-    EventKind::{Create, Modify, Remove},
-    PollWatcher, RecursiveMode, Watcher,
-};
+use metrics::{counter, gauge};
 use prost_types::FileDescriptorSet;
-use regex::Regex;
-use tonic::{body::BoxBody, Request};
 
-use tokio::task::JoinHandle;
+use tokio::{
+    sync::{broadcast, watch},
+    task::JoinHandle,
+    time::sleep,
+};
 
 // Store acts as the abstraction for the storage and retrieval of all features.
 // Internally there is a state machine that has two states: registered and unregistered.
 // A store starts unregistered, and a single register call will make it registered.
 // Once registered, a store will poll for new configuration and ignore subsequent register calls.
 //
-// We have an std RwLock around any concurrent state that we currently manage.
-// Std locks are fine to use in async functions as long as we don't hold them across
-// await's. This is enforced by the compiler, but just something to keep in mind.
+// Where versions come from is up to the ConfigSource the store is started with,
+// e.g. Lekko or a config repo on disk. The store only compiles, validates and
+// serves what the source loads into it.
+//
+// Each version of the repository is loaded into an immutable State, which is
+// published by atomically swapping it in. Readers never take a lock and never
+// copy features; they hold on to the State they loaded for as long as they need
+// it, and it is freed once the last reader is done with it.
+//
+// Code that needs to react to new versions can subscribe to the changes between
+// them.
 pub struct Store {
-    state: Arc<RwLock<ConcurrentState>>,
-    // keeping the join handle around keeps the poll watcher in scope,
-    // which is necessary to receive watch events from the filesystem.
-    _join_handle: Option<JoinHandle<PollWatcher>>,
+    state: Arc<SharedState>,
+    sync_status: Arc<SyncStatus>,
+    // Whether the source is still loading new versions, see supervise.
+    healthy: watch::Receiver<bool>,
+    // When the store started, which is how stale it is if no poll ever succeeded.
+    started: SystemTime,
+    // Loads new versions from the source, until the store is dropped.
+    source_task: JoinHandle<()>,
+}
+
+impl Drop for Store {
+    fn drop(&mut self) {
+        self.source_task.abort();
+    }
+}
+
+// Updates is how a ConfigSource loads new versions of the repository into the
+// store it feeds.
+pub struct Updates {
+    state: Arc<SharedState>,
+    sync_status: Arc<SyncStatus>,
+}
+
+impl Updates {
+    // Compiles the given contents and publishes them as the latest version of the
+    // repository. The features, commit sha and file descriptor set are replaced
+    // together, so that readers never see features with the types of another
+    // version. If the contents are invalid, they are rejected and the current
+    // version is kept.
+    pub fn load(&self, contents: &GetRepositoryContentsResponse) -> Result<(), tonic::Status> {
+        self.state.publish(self.state.latest().next(contents)?);
+        info!(
+            "loaded repo contents for commit sha {:}",
+            contents.commit_sha
+        );
+        self.sync_status.set_synced();
+        Ok(())
+    }
+
+    // The commit sha of the latest version that was loaded, which may not be the
+    // one being served if another version is pinned.
+    pub fn latest_version(&self) -> String {
+        self.state.latest().repo_version.to_string()
+    }
+
+    // The latest version that was loaded, in the form it was fetched in.
+    pub fn latest_contents(&self) -> GetRepositoryContentsResponse {
+        let latest = self.state.latest();
+        GetRepositoryContentsResponse {
+            commit_sha: latest.repo_version.to_string(),
+            namespaces: filter_cache(&latest.cache, "", ""),
+            file_descriptor_set: latest.file_descriptor_set.as_deref().cloned(),
+        }
+    }
+
+    // The namespaces the store keeps. Others don't need to be fetched.
+    pub fn namespaces(&self) -> Arc<NamespaceFilter> {
+        self.state.latest().namespaces.clone()
+    }
+
+    // Whether the store serves a cached snapshot that the source hasn't loaded
+    // contents since. Sources that sync with a remote should load the contents again
+    // even if their version didn't change.
+    pub fn is_degraded(&self) -> bool {
+        self.sync_status.degraded()
+    }
+
+    // Sources that sync with a remote report how that is going, which is exposed in
+    // metrics.
+    //
+    // Records that a sync with the remote succeeded.
+    pub fn record_success(&self) {
+        self.sync_status.record_success();
+    }
+
+    // Records that a sync with the remote failed at the given stage, after the given
+    // number of failures in a row.
+    pub fn record_failure(&self, stage: &'static str, consecutive_failures: u32) {
+        self.sync_status.record_failure(stage, consecutive_failures);
+    }
+}
+
+// How up to date the store is with its source, for sources that sync with a
+// remote such as Lekko. Shared with the source, which updates it.
+#[derive(Default)]
+struct SyncStatus {
+    // Whether the store started from a cached snapshot instead of from Lekko.
+    from_cache: AtomicBool,
+    // Whether contents were loaded from the source since the store started.
+    synced: AtomicBool,
+    // Number of polls that failed since the last one that succeeded.
+    consecutive_failures: AtomicU32,
+    // When the last successful poll finished, in milliseconds since the unix
+    // epoch, or 0 if no poll has succeeded yet.
+    last_success_millis: AtomicU64,
+}
+
+impl SyncStatus {
+    // A store is degraded while it serves a cached snapshot that Lekko hasn't
+    // confirmed is up to date.
+    fn degraded(&self) -> bool {
+        self.from_cache.load(Ordering::Relaxed) && !self.synced.load(Ordering::Relaxed)
+    }
+
+    fn set_synced(&self) {
+        if !self.synced.swap(true, Ordering::Relaxed) && self.from_cache.load(Ordering::Relaxed) {
+            info!("loaded contents from lekko, no longer serving the cached snapshot");
+        }
+        self.update_gauge();
+    }
+
+    fn update_gauge(&self) {
+        gauge!(DEGRADED_GAUGE, if self.degraded() { 1.0 } else { 0.0 });
+    }
+
+    fn record_success(&self) {
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+        self.last_success_millis
+            .store(now.as_millis() as u64, Ordering::Relaxed);
+        gauge!(POLL_CONSECUTIVE_FAILURES_GAUGE, 0.0);
+        gauge!(LAST_SUCCESSFUL_POLL_GAUGE, now.as_secs_f64());
+    }
+
+    fn record_failure(&self, stage: &'static str, consecutive_failures: u32) {
+        self.consecutive_failures
+            .store(consecutive_failures, Ordering::Relaxed);
+        counter!(POLL_FAILURE_COUNTER, 1, "stage" => stage);
+        gauge!(
+            POLL_CONSECUTIVE_FAILURES_GAUGE,
+            f64::from(consecutive_failures)
+        );
+    }
+
+    fn last_success(&self) -> Option<SystemTime> {
+        match self.last_success_millis.load(Ordering::Relaxed) {
+            0 => None,
+            millis => Some(SystemTime::UNIX_EPOCH + Duration::from_millis(millis)),
+        }
+    }
 }
 
-#[derive(PartialEq, Eq, Hash)]
-struct FeatureKey {
-    namespace: String,
-    feature: String,
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FeatureKey {
+    pub namespace: String,
+    pub feature: String,
 }
 
-struct FeatureInfo {
-    feature: Feature,
-    version: String,
+pub struct FeatureInfo {
+    pub feature: CompiledFeature,
+    // The sha of the feature's contents.
+    pub version: String,
 }
 
-type FeatureStore = HashMap<FeatureKey, FeatureInfo>;
+type FeatureStore = HashMap<FeatureKey, Arc<FeatureInfo>>;
 
-struct ConcurrentState {
+// The namespaces that a store fetches, keeps and serves. An empty filter includes
+// every namespace.
+#[derive(Clone, Debug, Default)]
+pub struct NamespaceFilter(BTreeSet<String>);
+
+impl NamespaceFilter {
+    pub fn new(namespaces: impl IntoIterator<Item = String>) -> Self {
+        Self(namespaces.into_iter().filter(|ns| !ns.is_empty()).collect())
+    }
+
+    pub fn includes(&self, namespace: &str) -> bool {
+        self.0.is_empty() || self.0.contains(namespace)
+    }
+
+    // The included namespaces, or None if every namespace is included.
+    pub fn namespaces(&self) -> Option<Vec<String>> {
+        (!self.0.is_empty()).then(|| self.0.iter().cloned().collect())
+    }
+}
+
+// A version of the repository. States are never modified once published.
+struct State {
     cache: FeatureStore,
-    repo_version: String,
-    file_descriptor_set: Option<FileDescriptorSet>,
+    repo_version: Arc<str>,
+    file_descriptor_set: Option<Arc<FileDescriptorSet>>,
+    namespaces: Arc<NamespaceFilter>,
 }
 
-pub struct FeatureData {
-    pub commit_sha: String,
-    pub feature_sha: String,
-    pub feature: Feature,
+impl State {
+    fn new(
+        contents: &GetRepositoryContentsResponse,
+        namespaces: Arc<NamespaceFilter>,
+    ) -> Result<Self, tonic::Status> {
+        Ok(Self {
+            cache: create_feature_store(
+                &contents.namespaces,
+                &namespaces,
+                contents.file_descriptor_set.as_ref(),
+            )?,
+            repo_version: contents.commit_sha.as_str().into(),
+            file_descriptor_set: contents.file_descriptor_set.clone().map(Arc::new),
+            namespaces,
+        })
+    }
+
+    // The next version of the repository, which keeps the namespaces of this one.
+    fn next(&self, contents: &GetRepositoryContentsResponse) -> Result<Self, tonic::Status> {
+        Self::new(contents, self.namespaces.clone())
+    }
 }
 
-lazy_static! {
-    // Lazily initialize static regex so we don't need to compile it repeatedly
-    static ref PROTO_BIN_FILE: Regex = match Regex::new(r"^.*/gen/proto/[\w.-]+\.proto\.bin$") {
-        Ok(r) => r,
-        Err(e) => panic!("failed to initialize regex {e:}"),
-    };
+// ChangeEvent describes how a new version of the repository differs from the
+// previous one. Features are compared by their shas.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChangeEvent {
+    pub old_commit_sha: String,
+    pub new_commit_sha: String,
+    pub added: BTreeSet<FeatureKey>,
+    pub removed: BTreeSet<FeatureKey>,
+    pub modified: BTreeSet<FeatureKey>,
 }
 
-async fn fs_watch(path: String, state: Arc<RwLock<ConcurrentState>>) -> PollWatcher {
-    let watch_path = path.clone();
-    let mut watcher = match notify::PollWatcher::new(
-        move |res: Result<Event, Error>| match res {
-            Ok(event) => {
-                match event.kind {
-                    Create(_) | Modify(_) | Remove(_) => {
-                        if any(&event.paths, |s| {
-                            match s.to_owned().into_os_string().into_string() {
-                                Ok(st) => PROTO_BIN_FILE.to_owned().is_match(&st),
-                                Err(e) => {
-                                    warn!("failed to convert path {e:?} to string");
-                                    false // don't reload contents for paths that aren't unicode
-                                }
-                            }
-                        }) {
-                            let path = path.clone();
-                            match RepoFS::new(path).and_then(|r| r.load()) {
-                                Ok(res) => {
-                                    {
-                                        // obtain lock again to replace data
-                                        let mut state_guard = state.write().unwrap();
-                                        state_guard.cache = create_feature_store(res.namespaces);
-                                        res.commit_sha.clone_into(&mut state_guard.repo_version);
-                                        // drop state_guard
-                                    }
-                                    info!(
-                                        "loaded repo contents for commit sha {:}",
-                                        res.commit_sha
-                                    );
-                                }
-                                Err(e) => {
-                                    warn!("failed to load repo contents from filesystem: {e:}")
-                                }
-                            };
-                        }
-                    }
-                    _ => (),
+impl ChangeEvent {
+    fn between(old: &State, new: &State) -> Self {
+        let mut event = Self {
+            old_commit_sha: old.repo_version.to_string(),
+            new_commit_sha: new.repo_version.to_string(),
+            ..Default::default()
+        };
+        for (key, info) in &new.cache {
+            match old.cache.get(key) {
+                None => {
+                    event.added.insert(key.clone());
                 }
+                Some(old_info) if old_info.version != info.version => {
+                    event.modified.insert(key.clone());
+                }
+                Some(_) => (),
             }
-            Err(e) => error!("fs watch error: {:?}", e),
-        },
-        notify::Config::default()
-            .with_compare_contents(false)
-            .with_poll_interval(Duration::from_secs(1)),
-    ) {
-        Ok(w) => w,
-        Err(e) => {
-            panic!("error initializing poll watcher {e:}")
         }
-    };
-    if let Err(e) = watcher.watch(Path::new(&watch_path), RecursiveMode::Recursive) {
-        panic!("error watching path {watch_path}: {e:}")
+        event.removed.extend(
+            old.cache
+                .keys()
+                .filter(|key| !new.cache.contains_key(key))
+                .cloned(),
+        );
+        event
+    }
+
+    fn is_empty(&self) -> bool {
+        self.old_commit_sha == self.new_commit_sha
+            && self.added.is_empty()
+            && self.removed.is_empty()
+            && self.modified.is_empty()
     }
-    watcher
 }
 
-async fn poll_loop(
-    dist_client: DistributionServiceClient<hyper::Client<HttpsConnector<HttpConnector>, BoxBody>>,
-    state: Arc<RwLock<ConcurrentState>>,
-    conn_creds: ConnectionCredentials,
-    poll_duration: Duration,
-) {
-    let mut interval = tokio::time::interval(poll_duration);
-    loop {
-        interval.tick().await;
-        // fetch version
-        let new_version =
-            match get_repo_version_remote(dist_client.clone(), conn_creds.clone()).await {
-                Ok(v) => v,
-                Err(err) => {
-                    // TODO: exp backoff when we have errors
-                    error!("got an error when fetching version {err:?}");
-                    continue;
-                }
-            };
+// How many change events a subscriber can fall behind by before it misses some.
+const CHANGE_CHANNEL_CAPACITY: usize = 64;
 
-        {
-            let state_guard = state.read().unwrap();
-            if state_guard.repo_version == new_version {
-                continue;
-            }
-            // release read lock to fetch data
-        };
+// The versions of the repository that were loaded most recently, and the one that
+// is pinned, if any.
+struct History {
+    // Oldest first, and never empty.
+    snapshots: VecDeque<Arc<State>>,
+    capacity: usize,
+    pinned: Option<Arc<State>>,
+}
 
-        info!("found new version: {new_version}, fetching");
+impl History {
+    fn latest(&self) -> &Arc<State> {
+        self.snapshots
+            .back()
+            .expect("history always has the initial version")
+    }
 
-        match get_repo_contents_remote(
-            dist_client.clone(),
-            conn_creds_to_repo_contents_request(conn_creds.clone()),
-        )
-        .await
-        {
-            Ok(res) => {
-                {
-                    // obtain lock again to replace data
-                    let mut state_guard = state.write().unwrap();
-                    state_guard.cache = create_feature_store(res.namespaces);
-                    res.commit_sha.clone_into(&mut state_guard.repo_version);
-                    // drop state_guard
-                }
-                info!("loaded repo contents for commit sha {:}", res.commit_sha);
-            }
-            Err(err) => {
-                // This is a problem, error loudly.
-                error!("error encountered when fetching full repository state: {err:?}",);
-            }
+    fn get(&self, commit_sha: &str) -> Option<&Arc<State>> {
+        self.snapshots
+            .iter()
+            .rev()
+            .chain(&self.pinned)
+            .find(|state| *state.repo_version == *commit_sha)
+    }
+}
+
+// The current version of the repository, shared by the store with the task that
+// keeps it up to date.
+//
+// The current version is usually the latest one that was loaded, unless an older
+// version is pinned. The last few versions are retained, so that they can be
+// inspected or pinned.
+struct SharedState {
+    current: ArcSwap<State>,
+    // Also serializes updates to current, so that subscribers see changes in order.
+    history: Mutex<History>,
+    // A broadcast rather than a watch channel, since every event has to be seen to
+    // know which features changed.
+    changes: broadcast::Sender<Arc<ChangeEvent>>,
+}
+
+impl SharedState {
+    fn new(state: State, history_capacity: usize) -> Self {
+        let state = Arc::new(state);
+        Self {
+            current: ArcSwap::new(state.clone()),
+            history: Mutex::new(History {
+                snapshots: VecDeque::from([state]),
+                capacity: history_capacity.max(1),
+                pinned: None,
+            }),
+            changes: broadcast::channel(CHANGE_CHANNEL_CAPACITY).0,
+        }
+    }
+
+    fn load(&self) -> Guard<Arc<State>> {
+        self.current.load()
+    }
+
+    fn load_full(&self) -> Arc<State> {
+        self.current.load_full()
+    }
+
+    fn history(&self) -> MutexGuard<'_, History> {
+        // Nothing that holds the lock can leave the history inconsistent.
+        self.history.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // The latest version that was loaded, which is the current version unless
+    // another version is pinned. New versions should be based on this one.
+    fn latest(&self) -> Arc<State> {
+        self.history().latest().clone()
+    }
+
+    // Records the given state as the latest version of the repository. It becomes
+    // the current version unless another version is pinned.
+    fn publish(&self, state: State) {
+        let state = Arc::new(state);
+        let mut history = self.history();
+        history
+            .snapshots
+            .retain(|s| s.repo_version != state.repo_version);
+        history.snapshots.push_back(state.clone());
+        while history.snapshots.len() > history.capacity {
+            history.snapshots.pop_front();
+        }
+        match &history.pinned {
+            Some(pinned) => info!(
+                "loaded commit sha {}, but still serving pinned commit sha {}",
+                state.repo_version, pinned.repo_version
+            ),
+            None => self.swap(state),
+        }
+    }
+
+    // Serves the retained version with the given commit sha until it is unpinned.
+    fn pin(&self, commit_sha: &str) -> Result<(), tonic::Status> {
+        let mut history = self.history();
+        let state = history.get(commit_sha).cloned().ok_or_else(|| {
+            tonic::Status::not_found(format!("commit sha {commit_sha} is not retained"))
+        })?;
+        history.pinned = Some(state.clone());
+        warn!("pinned commit sha {commit_sha}");
+        gauge!(PINNED_GAUGE, 1.0);
+        self.swap(state);
+        Ok(())
+    }
+
+    // Goes back to serving the latest version, returning its commit sha.
+    fn unpin(&self) -> Arc<str> {
+        let mut history = self.history();
+        if let Some(pinned) = history.pinned.take() {
+            warn!("unpinned commit sha {}", pinned.repo_version);
         }
+        gauge!(PINNED_GAUGE, 0.0);
+        let latest = history.latest().clone();
+        self.swap(latest.clone());
+        latest.repo_version.clone()
+    }
+
+    // Makes the given state the current version of the repository, and notifies
+    // subscribers of what changed. Must be called with the history locked.
+    fn swap(&self, state: Arc<State>) {
+        let old = self.current.swap(state.clone());
+        let event = ChangeEvent::between(&old, &state);
+        if !event.is_empty() {
+            // Sending only fails if nobody is subscribed.
+            let _ = self.changes.send(Arc::new(event));
+        }
+    }
+}
+
+pub struct FeatureData {
+    pub commit_sha: Arc<str>,
+    pub info: Arc<FeatureInfo>,
+}
+
+// A consistent read-only view of the store. Holding a snapshot guarantees that
+// all features read through it come from the same version of the repository,
+// which matters when evaluating features that reference other features.
+// Snapshots don't block updates to the store, but keep their version of the
+// repository in memory, so they shouldn't be kept around for longer than needed.
+pub struct StoreSnapshot {
+    state: Arc<State>,
+}
+
+impl StoreSnapshot {
+    // Looks up a feature. A feature in a namespace that this store doesn't load fails
+    // with FAILED_PRECONDITION rather than as not found, since it may well exist.
+    pub fn get_feature_local(
+        &self,
+        request: FeatureRequestParams,
+    ) -> Result<FeatureData, tonic::Status> {
+        if !self.state.namespaces.includes(&request.namespace) {
+            return Err(namespace_not_loaded(&request.namespace));
+        }
+        self.state
+            .cache
+            .get(&FeatureKey {
+                namespace: request.namespace,
+                feature: request.feature,
+            })
+            .map(|info| FeatureData {
+                commit_sha: self.state.repo_version.clone(),
+                info: info.clone(),
+            })
+            .ok_or_else(|| tonic::Status::invalid_argument("feature not found"))
+    }
+}
+
+fn namespace_not_loaded(namespace: &str) -> tonic::Status {
+    tonic::Status::failed_precondition(format!(
+        "namespace {namespace} is not loaded by this sidecar, see --namespaces"
+    ))
+}
+
+impl FeatureResolver for StoreSnapshot {
+    fn resolve(&self, namespace: &str, feature_name: &str) -> Option<&CompiledFeature> {
+        self.state
+            .cache
+            .get(&FeatureKey {
+                namespace: namespace.to_owned(),
+                feature: feature_name.to_owned(),
+            })
+            .map(|info| &info.feature)
     }
 }
 
-fn conn_creds_to_repo_contents_request(
-    conn_creds: ConnectionCredentials,
-) -> Request<GetRepositoryContentsRequest> {
-    let mut req = Request::new(GetRepositoryContentsRequest {
-        repo_key: Some(conn_creds.repo_key),
-        session_key: conn_creds.session_key,
-        // TODO: do namespaces correctly.
-        namespace_name: "".to_string(),
-        feature_name: "".to_string(),
-    });
-    req.metadata_mut().append(APIKEY, conn_creds.api_key);
-    req
+// Compiles every feature for evaluation as it is loaded, so that evaluation
+// doesn't need to do any work that only depends on the feature. Namespaces that
+// aren't included by the filter are skipped.
+//
+// Fails if any feature is invalid, so that the snapshot is rejected as a whole and
+// the current version keeps being served.
+fn create_feature_store(
+    namespaces: &[Namespace],
+    filter: &NamespaceFilter,
+    file_descriptor_set: Option<&FileDescriptorSet>,
+) -> Result<FeatureStore, tonic::Status> {
+    compile_features(namespaces, filter, file_descriptor_set).map_err(|e| {
+        counter!(SNAPSHOT_REJECTED_COUNTER, 1, "reason" => e.kind());
+        warn!("rejecting snapshot: {e}");
+        e.into()
+    })
 }
 
-fn create_feature_store(namespaces: Vec<Namespace>) -> FeatureStore {
+fn compile_features(
+    namespaces: &[Namespace],
+    filter: &NamespaceFilter,
+    file_descriptor_set: Option<&FileDescriptorSet>,
+) -> Result<FeatureStore, ValidationError> {
     // TODO add a flatmap here to only init once since we know the size beforehand.
     let mut feature_store = HashMap::new();
-    namespaces.into_iter().for_each(|namespace| {
-        namespace.features.into_iter().for_each(|feature| {
-            feature_store.insert(
-                FeatureKey {
-                    namespace: namespace.name.clone(),
-                    feature: feature.name,
-                },
-                FeatureInfo {
-                    feature: feature.feature.unwrap(),
-                    version: feature.sha,
-                },
-            );
-        })
-    });
-    feature_store
+    let mut interner = Interner::default();
+    let validator = Validator::new(file_descriptor_set);
+    for namespace in namespaces {
+        if !filter.includes(&namespace.name) {
+            continue;
+        }
+        for feature in &namespace.features {
+            let key = FeatureKey {
+                namespace: namespace.name.clone(),
+                feature: feature.name.clone(),
+            };
+            let err = |problem| ValidationError::new(&namespace.name, &feature.name, problem);
+            let proto = feature
+                .feature
+                .clone()
+                .ok_or_else(|| err(Problem::MissingFeature))?;
+            let info = FeatureInfo {
+                feature: CompiledFeature::new(&namespace.name, proto, &mut interner),
+                version: feature.sha.clone(),
+            };
+            validator.validate(&namespace.name, &info.feature)?;
+            feature_store.insert(key, Arc::new(info));
+        }
+    }
+    Ok(feature_store)
 }
 
-async fn get_repo_version_remote(
-    mut dist_client: DistributionServiceClient<
-        hyper::Client<HttpsConnector<HttpConnector>, BoxBody>,
-    >,
-    conn_creds: ConnectionCredentials,
-) -> Result<String, tonic::Status> {
-    dist_client
-        .get_repository_version(add_api_key(
-            GetRepositoryVersionRequest {
-                repo_key: Some(conn_creds.repo_key),
-                session_key: conn_creds.session_key,
-            },
-            conn_creds.api_key,
-        ))
-        .await
-        .map(|resp| resp.into_inner().commit_sha)
+// How long to wait before restarting a source that panicked, doubling with each
+// panic in a row.
+const SOURCE_RESTART_BACKOFF: Duration = Duration::from_secs(1);
+// The longest to wait before restarting a source. A source that runs this long
+// without panicking has recovered.
+const MAX_SOURCE_RESTART_BACKOFF: Duration = Duration::from_secs(60);
+// How many panics in a row make the store unhealthy.
+const UNHEALTHY_AFTER_RESTARTS: u32 = 3;
+
+// Watches the source until it returns or the store is dropped. If the source
+// panics, it is restarted after a backoff, so that a bug in one version of the
+// repository or a source doesn't stop updates for good. The store is reported
+// unhealthy while the source keeps panicking, and healthy again once it has run
+// for the recovery period without panicking.
+async fn supervise(
+    mut source: Box<dyn ConfigSource>,
+    updates: Updates,
+    mut backoff: Backoff,
+    recovery: Duration,
+    health: watch::Sender<bool>,
+) {
+    loop {
+        let watch = AssertUnwindSafe(source.watch(&updates)).catch_unwind();
+        tokio::pin!(watch);
+        let result = tokio::select! {
+            result = &mut watch => result,
+            _ = sleep(recovery) => {
+                if backoff.failures() > 0 {
+                    info!("config source recovered after {} restarts", backoff.failures());
+                }
+                backoff.reset();
+                health.send_if_modified(|healthy| !std::mem::replace(healthy, true));
+                watch.await
+            }
+        };
+        let Err(panic) = result else {
+            return;
+        };
+        let wait = backoff.fail();
+        counter!(SOURCE_RESTART_COUNTER, 1);
+        error!(
+            "config source panicked {} times in a row: {}, restarting in {wait:?}",
+            backoff.failures(),
+            panic_message(panic.as_ref())
+        );
+        if backoff.failures() >= UNHEALTHY_AFTER_RESTARTS {
+            health.send_if_modified(|healthy| std::mem::replace(healthy, false));
+        }
+        sleep(wait).await;
+    }
+}
+
+// Polls are stale once they have failed since the given time, for longer than
+// max_staleness.
+fn is_stale(
+    consecutive_failures: u32,
+    since: SystemTime,
+    now: SystemTime,
+    max_staleness: Duration,
+) -> bool {
+    consecutive_failures > 0
+        && now
+            .duration_since(since)
+            .map_or(false, |age| age > max_staleness)
 }
 
-async fn get_repo_contents_remote(
-    mut dist_client: DistributionServiceClient<
-        hyper::Client<HttpsConnector<HttpConnector>, BoxBody>,
-    >,
-    request: Request<GetRepositoryContentsRequest>,
-) -> Result<GetRepositoryContentsResponse, tonic::Status> {
-    dist_client
-        .get_repository_contents(request)
-        .await
-        .map(|resp| resp.into_inner())
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    match panic.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => panic
+            .downcast_ref::<String>()
+            .map_or("unknown panic", String::as_str),
+    }
 }
 
 impl Store {
+    // Starts a store from contents that were already loaded from the source, and
+    // follows the new versions the source loads into it.
     pub fn new(
-        dist_client: DistributionServiceClient<
-            hyper::Client<HttpsConnector<HttpConnector>, BoxBody>,
-        >,
         contents: GetRepositoryContentsResponse,
-        conn_creds: Option<ConnectionCredentials>,
-        poll_interval: Duration,
-        mode: Mode,
-        repo_path: String,
-    ) -> Self {
-        let state = Arc::new(RwLock::new(ConcurrentState {
-            cache: create_feature_store(contents.namespaces),
-            repo_version: contents.commit_sha,
-            file_descriptor_set: contents.file_descriptor_set,
-        }));
-        // Depending on the mode, we will either subscribe to dynamic updates
-        // from the filesystem (static mode), or from Lekko backend (default mode).
-        let jh = match mode {
-            Mode::Static => Some(tokio::spawn(fs_watch(repo_path, state.clone()))),
-            _ => {
-                // TODO: worry about this join handle.
-                tokio::spawn(poll_loop(
-                    dist_client,
-                    state.clone(),
-                    conn_creds.unwrap(),
-                    poll_interval,
-                ));
-                None
-            }
-        };
-        Self {
+        source: Box<dyn ConfigSource>,
+        namespaces: NamespaceFilter,
+        history_capacity: usize,
+    ) -> Result<Self, tonic::Status> {
+        let state = Arc::new(SharedState::new(
+            State::new(&contents, Arc::new(namespaces))?,
+            history_capacity,
+        ));
+        let sync_status = Arc::new(SyncStatus::default());
+        let (health, healthy) = watch::channel(true);
+        let source_task = tokio::spawn(supervise(
+            source,
+            Updates {
+                state: state.clone(),
+                sync_status: sync_status.clone(),
+            },
+            Backoff::new(SOURCE_RESTART_BACKOFF, MAX_SOURCE_RESTART_BACKOFF),
+            MAX_SOURCE_RESTART_BACKOFF,
+            health,
+        ));
+        Ok(Self {
             state,
-            _join_handle: jh,
+            sync_status,
+            healthy,
+            started: SystemTime::now(),
+            source_task,
+        })
+    }
+
+    // Starts a store from the current version of the source.
+    pub async fn from_source(
+        source: Box<dyn ConfigSource>,
+        namespaces: NamespaceFilter,
+        history_capacity: usize,
+    ) -> Result<Self, tonic::Status> {
+        let contents = source.load(&namespaces).await?;
+        Self::new(contents, source, namespaces, history_capacity)
+    }
+
+    // Marks the store as having started from a cached snapshot, because Lekko was
+    // unreachable. The store is degraded until the source loads contents from Lekko.
+    pub fn mark_from_cache(&self) {
+        self.sync_status.from_cache.store(true, Ordering::Relaxed);
+        self.sync_status.update_gauge();
+    }
+
+    // Subscribes to whether the store is healthy. It is unhealthy while its source
+    // keeps failing, since the served version may then be arbitrarily stale.
+    pub fn subscribe_health(&self) -> watch::Receiver<bool> {
+        self.healthy.clone()
+    }
+
+    // Number of polls for new versions that failed in a row. Always 0 in static mode.
+    pub fn consecutive_poll_failures(&self) -> u32 {
+        self.sync_status
+            .consecutive_failures
+            .load(Ordering::Relaxed)
+    }
+
+    // When a poll for new versions last succeeded, if ever.
+    pub fn last_successful_poll(&self) -> Option<SystemTime> {
+        self.sync_status.last_success()
+    }
+
+    // Whether polls for new versions have been failing for longer than max_staleness,
+    // so that the served version may be that far behind. Stores that don't poll are
+    // never stale.
+    pub fn is_stale(&self, max_staleness: Duration) -> bool {
+        is_stale(
+            self.consecutive_poll_failures(),
+            self.last_successful_poll().unwrap_or(self.started),
+            SystemTime::now(),
+            max_staleness,
+        )
+    }
+
+    pub fn get_feature_local(
+        &self,
+        request: FeatureRequestParams,
+    ) -> Result<FeatureData, tonic::Status> {
+        self.snapshot().get_feature_local(request)
+    }
+
+    pub fn snapshot(&self) -> StoreSnapshot {
+        StoreSnapshot {
+            state: self.state.load_full(),
         }
     }
 
-    pub fn get_feature_local(&self, request: FeatureRequestParams) -> Option<FeatureData> {
-        let ConcurrentState {
-            cache,
-            repo_version,
-            file_descriptor_set: _,
-        } = &*self.state.read().unwrap();
-        cache
-            .get(&FeatureKey {
-                namespace: request.namespace.clone(),
-                feature: request.feature,
-            })
-            .map(|feature| FeatureData {
-                feature: feature.feature.clone(),
-                commit_sha: repo_version.clone(),
-                feature_sha: feature.version.clone(),
+    // A snapshot of a retained version of the repository, for debugging.
+    pub fn snapshot_at(&self, commit_sha: &str) -> Option<StoreSnapshot> {
+        self.state
+            .history()
+            .get(commit_sha)
+            .map(|state| StoreSnapshot {
+                state: state.clone(),
             })
     }
 
+    // The commit shas of the retained versions of the repository, oldest first.
+    pub fn retained_versions(&self) -> Vec<String> {
+        self.state
+            .history()
+            .snapshots
+            .iter()
+            .map(|state| state.repo_version.to_string())
+            .collect()
+    }
+
+    pub fn pinned_version(&self) -> Option<String> {
+        self.state
+            .history()
+            .pinned
+            .as_ref()
+            .map(|state| state.repo_version.to_string())
+    }
+
+    // Serves the retained version with the given commit sha, even after newer
+    // versions are loaded, until unpin is called. Fails with NOT_FOUND if the
+    // version isn't retained.
+    pub fn pin(&self, commit_sha: &str) -> Result<(), tonic::Status> {
+        self.state.pin(commit_sha)
+    }
+
+    // Goes back to serving the latest version, returning its commit sha.
+    pub fn unpin(&self) -> String {
+        self.state.unpin().to_string()
+    }
+
+    // Compiles the given contents and publishes them as the current version of the
+    // repository. Evaluations that are in progress finish on the version they started
+    // with. If the contents can't be compiled, the current version is kept.
+    pub fn load(&self, contents: GetRepositoryContentsResponse) -> Result<(), tonic::Status> {
+        self.state.publish(self.state.latest().next(&contents)?);
+        Ok(())
+    }
+
+    // Subscribes to changes to the repository. Each new version is sent as the
+    // changes from the version before it. Subscribers that fall too far behind
+    // receive a RecvError::Lagged, and should reload whatever they derived from
+    // the store.
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<ChangeEvent>> {
+        self.state.changes.subscribe()
+    }
+
     pub fn get_version_local(&self) -> String {
-        return (*self.state.read().unwrap().repo_version).to_owned();
+        self.state.load().repo_version.to_string()
     }
 
+    // Returns the loaded contents, optionally filtered to a namespace and feature.
+    // Fails if the requested namespace isn't loaded by this store.
     pub fn get_repo_contents_local(
         &self,
         namespace_filter: &str,
         feature_filter: &str,
-    ) -> (String, Vec<Namespace>, Option<FileDescriptorSet>) {
-        let ConcurrentState {
-            cache,
-            repo_version,
-            file_descriptor_set,
-        } = &*self.state.read().unwrap();
-
-        (
-            repo_version.clone(),
-            filter_cache(cache, namespace_filter, feature_filter),
-            file_descriptor_set.clone(),
-        )
+    ) -> Result<(String, Vec<Namespace>, Option<FileDescriptorSet>), tonic::Status> {
+        let state = self.state.load();
+        if !namespace_filter.is_empty() && !state.namespaces.includes(namespace_filter) {
+            return Err(namespace_not_loaded(namespace_filter));
+        }
+        Ok((
+            state.repo_version.to_string(),
+            filter_cache(&state.cache, namespace_filter, feature_filter),
+            state.file_descriptor_set.as_deref().cloned(),
+        ))
     }
 }
 
@@ -357,7 +821,7 @@ fn filter_cache(
                     .push(backend::v1beta1::Feature {
                         name: feature_key.feature.clone(),
                         sha: feature.version.clone(),
-                        feature: Some(feature.feature.clone()),
+                        feature: Some(feature.feature.proto().clone()),
                     });
                 vec_map
             },
@@ -369,7 +833,10 @@ fn filter_cache(
 
 #[cfg(test)]
 mod tests {
+    use tokio::sync::mpsc;
+
     use super::*;
+    use crate::gen::cli::lekko::feature::v1beta1::{Feature, Tree};
     #[test]
     fn test_filter() {
         let mut cache = FeatureStore::new();
@@ -386,10 +853,10 @@ mod tests {
                     namespace: ns.to_owned(),
                     feature: feat.to_owned(),
                 },
-                FeatureInfo {
-                    feature: Feature::default(),
+                Arc::new(FeatureInfo {
+                    feature: CompiledFeature::new(ns, Feature::default(), &mut Interner::default()),
                     version: feat.to_owned(),
-                },
+                }),
             );
         }
         let res = filter_cache(&cache, "", "");
@@ -401,4 +868,397 @@ mod tests {
         assert_eq!(res.len(), 1);
         assert_eq!(res[0].features.len(), 1);
     }
+
+    // A version of the repository with the given (namespace, feature, sha)s.
+    fn contents(
+        commit_sha: &str,
+        features: &[(&str, &str, &str)],
+    ) -> GetRepositoryContentsResponse {
+        let namespaces = features
+            .iter()
+            .map(|(ns, name, sha)| Namespace {
+                name: ns.to_string(),
+                features: vec![backend::v1beta1::Feature {
+                    name: name.to_string(),
+                    sha: sha.to_string(),
+                    feature: Some(Feature {
+                        key: name.to_string(),
+                        tree: Some(Tree {
+                            default: Some(prost_types::Any {
+                                type_url: String::from(
+                                    "type.googleapis.com/google.protobuf.BoolValue",
+                                ),
+                                value: vec![],
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                }],
+            })
+            .collect();
+        GetRepositoryContentsResponse {
+            commit_sha: commit_sha.to_owned(),
+            namespaces,
+            file_descriptor_set: None,
+        }
+    }
+
+    fn state(commit_sha: &str, features: &[(&str, &str, &str)]) -> State {
+        State::new(&contents(commit_sha, features), Arc::default()).unwrap()
+    }
+
+    fn keys(keys: &[(&str, &str)]) -> BTreeSet<FeatureKey> {
+        keys.iter()
+            .map(|(ns, feature)| FeatureKey {
+                namespace: ns.to_string(),
+                feature: feature.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_change_events() {
+        let shared = SharedState::new(
+            state(
+                "v1",
+                &[("ns1", "a", "1"), ("ns1", "b", "1"), ("ns2", "c", "1")],
+            ),
+            1,
+        );
+        let mut changes = shared.changes.subscribe();
+        shared.publish(state(
+            "v2",
+            &[("ns1", "a", "1"), ("ns1", "b", "2"), ("ns2", "d", "1")],
+        ));
+        assert_eq!(
+            *changes.try_recv().unwrap(),
+            ChangeEvent {
+                old_commit_sha: String::from("v1"),
+                new_commit_sha: String::from("v2"),
+                added: keys(&[("ns2", "d")]),
+                removed: keys(&[("ns2", "c")]),
+                modified: keys(&[("ns1", "b")]),
+            }
+        );
+        assert_eq!(*shared.load().repo_version, *"v2");
+        // reloading the same version isn't a change
+        shared.publish(state(
+            "v2",
+            &[("ns1", "a", "1"), ("ns1", "b", "2"), ("ns2", "d", "1")],
+        ));
+        assert!(changes.try_recv().is_err());
+        // a new commit that doesn't touch any features still is
+        shared.publish(state(
+            "v3",
+            &[("ns1", "a", "1"), ("ns1", "b", "2"), ("ns2", "d", "1")],
+        ));
+        let event = changes.try_recv().unwrap();
+        assert_eq!(event.new_commit_sha, "v3");
+        assert!(event.added.is_empty() && event.removed.is_empty() && event.modified.is_empty());
+    }
+
+    #[test]
+    fn test_namespace_filter() {
+        let filter = NamespaceFilter::new([String::from("ns1"), String::from("")]);
+        assert!(filter.includes("ns1") && !filter.includes("ns2"));
+        assert_eq!(filter.namespaces(), Some(vec![String::from("ns1")]));
+        assert!(NamespaceFilter::default().includes("ns2"));
+        assert_eq!(NamespaceFilter::default().namespaces(), None);
+
+        let state = State::new(
+            &contents("v1", &[("ns1", "a", "1"), ("ns2", "b", "1")]),
+            Arc::new(filter),
+        )
+        .unwrap();
+        assert_eq!(state.cache.len(), 1);
+        let snapshot = StoreSnapshot {
+            state: Arc::new(state),
+        };
+        let request = |namespace: &str, feature: &str| FeatureRequestParams {
+            rk: Default::default(),
+            namespace: namespace.to_owned(),
+            feature: feature.to_owned(),
+        };
+        assert!(snapshot.get_feature_local(request("ns1", "a")).is_ok());
+        let err = snapshot
+            .get_feature_local(request("ns1", "b"))
+            .err()
+            .unwrap();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        // features in namespaces that aren't loaded aren't reported as not found
+        let err = snapshot
+            .get_feature_local(request("ns2", "b"))
+            .err()
+            .unwrap();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+    }
+
+    #[test]
+    fn test_history_and_pinning() {
+        let shared = SharedState::new(state("v1", &[("ns", "a", "1")]), 3);
+        let mut changes = shared.changes.subscribe();
+        let versions = |shared: &SharedState| {
+            let history = shared.history();
+            history
+                .snapshots
+                .iter()
+                .map(|s| s.repo_version.to_string())
+                .collect::<Vec<_>>()
+        };
+        shared.publish(state("v2", &[("ns", "a", "2")]));
+        shared.publish(state("v3", &[("ns", "a", "3")]));
+        assert_eq!(versions(&shared), ["v1", "v2", "v3"]);
+        shared.publish(state("v4", &[("ns", "a", "4")]));
+        assert_eq!(versions(&shared), ["v2", "v3", "v4"]);
+        assert!(shared.history().get("v1").is_none());
+        assert_eq!(*shared.history().get("v3").unwrap().repo_version, *"v3");
+        while changes.try_recv().is_ok() {}
+
+        assert_eq!(shared.pin("v1").unwrap_err().code(), tonic::Code::NotFound);
+        shared.pin("v2").unwrap();
+        assert_eq!(*shared.load().repo_version, *"v2");
+        assert_eq!(changes.try_recv().unwrap().new_commit_sha, "v2");
+        // newer versions are retained, but not served, while pinned
+        shared.publish(state("v5", &[("ns", "a", "5")]));
+        shared.publish(state("v6", &[("ns", "a", "6")]));
+        shared.publish(state("v7", &[("ns", "a", "7")]));
+        assert_eq!(*shared.load().repo_version, *"v2");
+        assert_eq!(*shared.latest().repo_version, *"v7");
+        assert!(changes.try_recv().is_err());
+        // the pinned version can still be looked up after it is no longer retained
+        assert!(shared.history().get("v2").is_some());
+
+        assert_eq!(*shared.unpin(), *"v7");
+        assert_eq!(*shared.load().repo_version, *"v7");
+        let event = changes.try_recv().unwrap();
+        assert_eq!(
+            (&*event.old_commit_sha, &*event.new_commit_sha),
+            ("v2", "v7")
+        );
+        assert!(shared.history().get("v2").is_none());
+    }
+
+    #[test]
+    fn test_reject_invalid_snapshot() {
+        let shared = SharedState::new(state("v1", &[("ns", "a", "1")]), 1);
+        let mut invalid = contents("v2", &[("ns", "a", "2"), ("ns", "b", "1")]);
+        invalid.namespaces[1].features[0].feature = None;
+        let err = shared.latest().next(&invalid).err().unwrap();
+        assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+        assert_eq!(
+            err.message(),
+            "invalid feature ns/b: missing feature contents"
+        );
+        // the last good version is kept
+        assert_eq!(*shared.load().repo_version, *"v1");
+    }
+
+    #[test]
+    fn test_load_type_mismatched_rule() {
+        use crate::evaluate::{
+            error::EvalError,
+            evaluator::{evaluate, EvalContext},
+        };
+        use crate::gen::cli::lekko::feature::v1beta1::Constraint;
+        use crate::gen::cli::lekko::rules::v1beta3::{
+            rule::Rule as RuleKind, Atom, ComparisonOperator, Rule,
+        };
+        use crate::gen::sdk::lekko::client::v1beta1::{value::Kind, Value};
+        use crate::types::TypeMismatchPolicy;
+
+        // starts with can't be used with a number, but the atom only fails if reached
+        let mut contents = contents("v1", &[("ns", "a", "1")]);
+        let tree = contents.namespaces[0].features[0]
+            .feature
+            .as_mut()
+            .unwrap()
+            .tree
+            .as_mut()
+            .unwrap();
+        tree.constraints = vec![Constraint {
+            value: tree.default.clone(),
+            rule_ast_new: Some(Rule {
+                rule: Some(RuleKind::Atom(Atom {
+                    context_key: String::from("key"),
+                    comparison_value: Some(prost_types::Value {
+                        kind: Some(prost_types::value::Kind::NumberValue(1.0)),
+                    }),
+                    comparison_operator: ComparisonOperator::StartsWith.into(),
+                })),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }];
+        let state = State::new(&contents, Arc::default()).unwrap();
+        let feature = &state.cache[&FeatureKey {
+            namespace: String::from("ns"),
+            feature: String::from("a"),
+        }]
+            .feature;
+        let context = HashMap::from([(
+            String::from("key"),
+            Value {
+                kind: Some(Kind::StringValue(String::from("value"))),
+            },
+        )]);
+        let eval_context = |type_mismatch_policy| EvalContext {
+            type_mismatch_policy,
+            ..Default::default()
+        };
+        // the constraint doesn't pass, so the default is returned
+        let (_, path) =
+            evaluate(feature, &context, &eval_context(TypeMismatchPolicy::False)).unwrap();
+        assert!(path.is_empty());
+        assert!(matches!(
+            evaluate(feature, &context, &eval_context(TypeMismatchPolicy::Error)),
+            Err(EvalError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_degraded_until_loaded() {
+        let updates = Updates {
+            state: Arc::new(SharedState::new(state("v1", &[("ns", "a", "1")]), 1)),
+            sync_status: Arc::default(),
+        };
+        updates
+            .sync_status
+            .from_cache
+            .store(true, Ordering::Relaxed);
+        assert!(updates.is_degraded());
+        // reaching the remote isn't enough, the cached contents have to be replaced
+        updates.record_success();
+        assert!(updates.is_degraded());
+        let mut invalid = contents("v2", &[("ns", "a", "2")]);
+        invalid.namespaces[0].features[0].feature = None;
+        assert!(updates.load(&invalid).is_err());
+        assert!(updates.is_degraded());
+        updates.load(&contents("v1", &[("ns", "a", "1")])).unwrap();
+        assert!(!updates.is_degraded());
+    }
+
+    #[test]
+    fn test_is_stale() {
+        let since = SystemTime::UNIX_EPOCH + Duration::from_secs(1000);
+        let max = Duration::from_secs(60);
+        let after = |secs| since + Duration::from_secs(secs);
+        assert!(!is_stale(0, since, after(3600), max));
+        assert!(!is_stale(1, since, after(60), max));
+        assert!(is_stale(1, since, after(61), max));
+        assert!(is_stale(5, since, after(3600), max));
+        // a clock that went backwards isn't stale
+        assert!(!is_stale(5, since, since - Duration::from_secs(1), max));
+    }
+
+    // A source that loads whatever versions are sent to it.
+    struct ChannelSource {
+        initial: GetRepositoryContentsResponse,
+        versions: mpsc::Receiver<GetRepositoryContentsResponse>,
+    }
+
+    #[tonic::async_trait]
+    impl ConfigSource for ChannelSource {
+        async fn load(
+            &self,
+            _namespaces: &NamespaceFilter,
+        ) -> Result<GetRepositoryContentsResponse, tonic::Status> {
+            Ok(self.initial.clone())
+        }
+
+        async fn watch(&mut self, updates: &Updates) {
+            while let Some(contents) = self.versions.recv().await {
+                let _ = updates.load(&contents);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_source() {
+        let (tx, versions) = mpsc::channel(1);
+        let source = ChannelSource {
+            initial: contents("v1", &[("ns", "a", "1")]),
+            versions,
+        };
+        let store = Store::from_source(Box::new(source), NamespaceFilter::default(), 10)
+            .await
+            .unwrap();
+        assert_eq!(store.get_version_local(), "v1");
+
+        let mut changes = store.subscribe();
+        tx.send(contents("v2", &[("ns", "a", "2")])).await.unwrap();
+        assert_eq!(changes.recv().await.unwrap().new_commit_sha, "v2");
+        // invalid versions are rejected by the store, not the source
+        let mut invalid = contents("v3", &[("ns", "a", "3")]);
+        invalid.namespaces[0].features[0].feature = None;
+        tx.send(invalid).await.unwrap();
+        tx.send(contents("v4", &[("ns", "a", "4")])).await.unwrap();
+        assert_eq!(changes.recv().await.unwrap().new_commit_sha, "v4");
+        assert_eq!(store.retained_versions(), ["v1", "v2", "v4"]);
+        // polls that keep failing make the store stale
+        assert!(!store.is_stale(Duration::ZERO));
+        store.sync_status.record_failure("version", 1);
+        sleep(Duration::from_millis(10)).await;
+        assert!(store.is_stale(Duration::from_millis(1)));
+        store.sync_status.record_success();
+        assert!(!store.is_stale(Duration::ZERO));
+
+        // the source stops when the store is dropped
+        drop(store);
+        tx.closed().await;
+    }
+
+    // A source that panics the first few times it is watched, and then loads a new
+    // version.
+    struct PanickingSource {
+        panics: u32,
+    }
+
+    #[tonic::async_trait]
+    impl ConfigSource for PanickingSource {
+        async fn load(
+            &self,
+            _namespaces: &NamespaceFilter,
+        ) -> Result<GetRepositoryContentsResponse, tonic::Status> {
+            Ok(contents("v1", &[("ns", "a", "1")]))
+        }
+
+        async fn watch(&mut self, updates: &Updates) {
+            if self.panics > 0 {
+                self.panics -= 1;
+                panic!("source failed");
+            }
+            updates.load(&contents("v2", &[("ns", "a", "2")])).unwrap();
+            std::future::pending::<()>().await;
+        }
+    }
+
+    async fn wait_for_health(healthy: &mut watch::Receiver<bool>, want: bool) {
+        while *healthy.borrow_and_update() != want {
+            healthy.changed().await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_supervise() {
+        let shared = Arc::new(SharedState::new(state("v1", &[("ns", "a", "1")]), 1));
+        let (health, mut healthy) = watch::channel(true);
+        let task = tokio::spawn(supervise(
+            Box::new(PanickingSource {
+                panics: UNHEALTHY_AFTER_RESTARTS,
+            }),
+            Updates {
+                state: shared.clone(),
+                sync_status: Arc::default(),
+            },
+            Backoff::new(Duration::from_millis(1), Duration::from_millis(1)),
+            Duration::from_millis(100),
+            health,
+        ));
+        // repeated panics make the store unhealthy, but the source keeps being restarted
+        wait_for_health(&mut healthy, false).await;
+        wait_for_health(&mut healthy, true).await;
+        assert_eq!(*shared.load().repo_version, *"v2");
+        task.abort();
+    }
 }
@@ -1,5 +1,8 @@
+use std::sync::Arc;
+
 use crate::gen::cli::lekko::backend::v1beta1::RepositoryKey;
 use crate::gen::sdk::lekko::client::v1beta1::RepositoryKey as PublicRepositoryKey;
+use arc_swap::ArcSwap;
 use prost::{DecodeError, Message};
 use prost_types::Any;
 use tonic::metadata::{Ascii, MetadataValue};
@@ -50,18 +53,70 @@ pub fn convert_repo_key(rk: &PublicRepositoryKey) -> RepositoryKey {
 //
 // Static fetches from the bootstrap and always evaluates against those values. No
 // connection is made to Lekko services.
+//
+// Snapshot is like static, but loads a snapshot file instead of a config repo, e.g.
+// one persisted by another sidecar's cache.
 #[derive(clap::ValueEnum, Clone, Default, Debug)]
 pub enum Mode {
     #[default]
     Default,
     Static,
+    Snapshot,
+}
+
+// TypeMismatchPolicy determines how a rule is evaluated when the type of a context
+// value does not match the type the rule compares it against.
+//
+// Error fails the evaluation, and is the default.
+//
+// False treats the mismatched comparison as not passing, and continues evaluating.
+#[derive(clap::ValueEnum, Clone, Copy, Default, Debug, PartialEq)]
+pub enum TypeMismatchPolicy {
+    #[default]
+    Error,
+    False,
+}
+
+// WatchMode determines how the repository or snapshot file is watched for changes
+// in static and snapshot mode.
+//
+// Native uses the filesystem's change notifications, e.g. inotify on Linux, and is
+// the default.
+//
+// Poll scans the repository every second, for filesystems that don't deliver
+// change notifications, such as some network filesystems.
+#[derive(clap::ValueEnum, Clone, Copy, Default, Debug, PartialEq)]
+pub enum WatchMode {
+    #[default]
+    Native,
+    Poll,
 }
 
 #[derive(Clone)]
 pub struct ConnectionCredentials {
     pub repo_key: RepositoryKey,
     pub api_key: MetadataValue<Ascii>,
-    pub session_key: String,
+    pub session_key: SessionKey,
+}
+
+// Session key handed out by the backend on registration. Clones share the
+// same key, so a registration that only succeeds after startup is seen by
+// everything holding the connection credentials.
+#[derive(Clone, Default)]
+pub struct SessionKey(Arc<ArcSwap<String>>);
+
+impl SessionKey {
+    pub fn new(session_key: String) -> Self {
+        Self(Arc::new(ArcSwap::from_pointee(session_key)))
+    }
+
+    pub fn get(&self) -> String {
+        self.0.load().as_ref().clone()
+    }
+
+    pub fn set(&self, session_key: String) {
+        self.0.store(Arc::new(session_key));
+    }
 }
 
 pub fn add_api_key<T: Message>(m: T, api_key: MetadataValue<Ascii>) -> tonic::Request<T> {
@@ -105,6 +160,7 @@ mod tests {
     use crate::types::override_api_key;
 
     use crate::types::ConnectionCredentials;
+    use crate::types::SessionKey;
     use crate::types::APIKEY;
 
     #[test]
@@ -160,7 +216,7 @@ mod tests {
         });
 
         let cc = ConnectionCredentials {
-            session_key: "".to_string(),
+            session_key: SessionKey::default(),
             repo_key: rk,
             api_key: AsciiMetadataValue::from_static("some"),
         };
@@ -188,7 +244,7 @@ mod tests {
         );
 
         let cc = ConnectionCredentials {
-            session_key: "".to_string(),
+            session_key: SessionKey::default(),
             repo_key: rk,
             api_key: AsciiMetadataValue::from_static("some"),
         };
@@ -0,0 +1,347 @@
+use std::{collections::HashSet, fmt};
+
+use prost_types::{Any, DescriptorProto, FileDescriptorSet};
+
+use crate::{
+    evaluate::{
+        error::EvalError,
+        plan::{CompiledConstraint, CompiledFeature},
+    },
+    gen::cli::lekko::feature::v1beta1::FeatureType,
+};
+
+// ValidationError is why a feature makes a snapshot of the repository invalid.
+// Invalid snapshots are rejected as a whole, and the last valid one is kept.
+#[derive(Debug, PartialEq)]
+pub struct ValidationError {
+    pub namespace: String,
+    pub feature: String,
+    pub problem: Problem,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Problem {
+    // The repository listed the feature without its contents.
+    MissingFeature,
+    // The feature has no tree or default value, has a rule that can never be
+    // evaluated, or can't be compiled.
+    Invalid(EvalError),
+    // A value's type isn't in the file descriptor set.
+    UnknownType(String),
+    // A value's type doesn't match the declared type of the feature.
+    TypeMismatch {
+        declared: FeatureType,
+        type_url: String,
+    },
+}
+
+impl ValidationError {
+    pub fn new(namespace: &str, feature: &str, problem: Problem) -> Self {
+        Self {
+            namespace: namespace.to_owned(),
+            feature: feature.to_owned(),
+            problem,
+        }
+    }
+
+    // A short, stable name for the kind of error, e.g. for metric labels.
+    pub fn kind(&self) -> &'static str {
+        match &self.problem {
+            Problem::MissingFeature => "missing_feature",
+            Problem::Invalid(e) => e.kind(),
+            Problem::UnknownType(_) => "unknown_type",
+            Problem::TypeMismatch { .. } => "value_type_mismatch",
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid feature {}/{}: ", self.namespace, self.feature)?;
+        match &self.problem {
+            Problem::MissingFeature => write!(f, "missing feature contents"),
+            Problem::Invalid(e) => write!(f, "{e}"),
+            Problem::UnknownType(type_url) => {
+                write!(f, "type {type_url} is not in the file descriptor set")
+            }
+            Problem::TypeMismatch { declared, type_url } => write!(
+                f,
+                "value of type {type_url} in feature of type {}",
+                declared.as_str_name()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl From<ValidationError> for tonic::Status {
+    fn from(e: ValidationError) -> Self {
+        tonic::Status::failed_precondition(e.to_string())
+    }
+}
+
+// Validator checks compiled features against the types of the snapshot they
+// belong to.
+pub struct Validator {
+    // The fully qualified names of the messages in the file descriptor set, or
+    // None if the snapshot doesn't have one, in which case types aren't resolved.
+    types: Option<HashSet<String>>,
+}
+
+impl Validator {
+    pub fn new(file_descriptor_set: Option<&FileDescriptorSet>) -> Self {
+        Self {
+            types: file_descriptor_set.map(|fds| {
+                let mut types = HashSet::new();
+                for file in &fds.file {
+                    collect_types(file.package(), &file.message_type, &mut types);
+                }
+                types
+            }),
+        }
+    }
+
+    pub fn validate(
+        &self,
+        namespace: &str,
+        feature: &CompiledFeature,
+    ) -> Result<(), ValidationError> {
+        let proto = feature.proto();
+        let err = |problem| ValidationError::new(namespace, &proto.key, problem);
+        if proto.tree.is_none() {
+            return Err(err(Problem::Invalid(EvalError::MissingTree)));
+        }
+        let default = feature
+            .default
+            .as_ref()
+            .ok_or_else(|| err(Problem::Invalid(EvalError::MissingDefault)))?;
+        let mut values = vec![default];
+        for constraint in &feature.constraints {
+            walk(constraint, &mut |c| {
+                if let Some(e) = c.rule.first_invalid() {
+                    return Err(err(Problem::Invalid(e.clone())));
+                }
+                values.extend(&c.value);
+                Ok(())
+            })?;
+        }
+        for value in values {
+            self.check_type(proto.r#type(), default, value)
+                .map_err(err)?;
+        }
+        Ok(())
+    }
+
+    fn check_type(&self, declared: FeatureType, default: &Any, value: &Any) -> Result<(), Problem> {
+        let type_name = value.type_url.rsplit('/').next().unwrap_or_default();
+        let expected = match declared {
+            FeatureType::Bool => Some("google.protobuf.BoolValue"),
+            FeatureType::Int => Some("google.protobuf.Int64Value"),
+            FeatureType::Float => Some("google.protobuf.DoubleValue"),
+            FeatureType::String => Some("google.protobuf.StringValue"),
+            FeatureType::Json => Some("google.protobuf.Value"),
+            FeatureType::Proto | FeatureType::Unspecified => None,
+        };
+        let mismatch = match expected {
+            Some(expected) => type_name != expected,
+            // Every value of a proto feature is the same message.
+            None => declared == FeatureType::Proto && value.type_url != default.type_url,
+        };
+        if mismatch {
+            return Err(Problem::TypeMismatch {
+                declared,
+                type_url: value.type_url.clone(),
+            });
+        }
+        // Well known types are always available to the SDKs, even if the file
+        // descriptor set doesn't include them.
+        let resolves = type_name.starts_with("google.protobuf.")
+            || self
+                .types
+                .as_ref()
+                .map_or(true, |types| types.contains(type_name));
+        if !resolves {
+            return Err(Problem::UnknownType(value.type_url.clone()));
+        }
+        Ok(())
+    }
+}
+
+fn collect_types(prefix: &str, messages: &[DescriptorProto], types: &mut HashSet<String>) {
+    for message in messages {
+        let name = match prefix {
+            "" => message.name().to_owned(),
+            prefix => format!("{prefix}.{}", message.name()),
+        };
+        collect_types(&name, &message.nested_type, types);
+        types.insert(name);
+    }
+}
+
+// Calls f on the constraint and every constraint nested in it.
+fn walk<'a, E>(
+    constraint: &'a CompiledConstraint,
+    f: &mut impl FnMut(&'a CompiledConstraint) -> Result<(), E>,
+) -> Result<(), E> {
+    f(constraint)?;
+    for nested in &constraint.constraints {
+        walk(nested, f)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::FileDescriptorProto;
+
+    use super::*;
+    use crate::evaluate::plan::Interner;
+    use crate::gen::cli::lekko::feature::v1beta1::{Constraint, Feature, Tree};
+    use crate::gen::cli::lekko::rules::v1beta3::{rule::Rule as RuleKind, Atom, Rule};
+
+    fn any(type_name: &str) -> Any {
+        Any {
+            type_url: format!("type.googleapis.com/{type_name}"),
+            value: vec![],
+        }
+    }
+
+    fn constraint(value: Any, rule: RuleKind) -> Constraint {
+        Constraint {
+            value: Some(value),
+            rule_ast_new: Some(Rule {
+                rule: Some(rule),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn feature(
+        r#type: FeatureType,
+        default: Option<Any>,
+        constraints: Vec<Constraint>,
+    ) -> CompiledFeature {
+        let feature = Feature {
+            key: String::from("feature"),
+            tree: Some(Tree {
+                default,
+                constraints,
+                ..Default::default()
+            }),
+            r#type: r#type.into(),
+            ..Default::default()
+        };
+        CompiledFeature::new("ns", feature, &mut Interner::default())
+    }
+
+    fn problem(validator: &Validator, feature: &CompiledFeature) -> Option<Problem> {
+        validator.validate("ns", feature).err().map(|e| e.problem)
+    }
+
+    #[test]
+    fn test_validate() {
+        let validator = Validator::new(None);
+        let bool_value = any("google.protobuf.BoolValue");
+        let valid = feature(
+            FeatureType::Bool,
+            Some(bool_value.clone()),
+            vec![constraint(bool_value.clone(), RuleKind::BoolConst(true))],
+        );
+        assert_eq!(problem(&validator, &valid), None);
+
+        let mut no_tree = valid.proto().clone();
+        no_tree.tree = None;
+        let no_tree = CompiledFeature::new("ns", no_tree, &mut Interner::default());
+        assert_eq!(
+            problem(&validator, &no_tree),
+            Some(Problem::Invalid(EvalError::MissingTree))
+        );
+        assert_eq!(
+            problem(&validator, &feature(FeatureType::Bool, None, vec![])),
+            Some(Problem::Invalid(EvalError::MissingDefault))
+        );
+
+        let mismatched = feature(
+            FeatureType::Bool,
+            Some(bool_value.clone()),
+            vec![constraint(
+                any("google.protobuf.StringValue"),
+                RuleKind::BoolConst(true),
+            )],
+        );
+        let err = validator.validate("ns", &mismatched).unwrap_err();
+        assert_eq!(err.kind(), "value_type_mismatch");
+        assert_eq!(
+            err.to_string(),
+            "invalid feature ns/feature: value of type type.googleapis.com/google.protobuf.StringValue in feature of type FEATURE_TYPE_BOOL"
+        );
+
+        // nested constraints are checked too
+        let mut nested = constraint(bool_value.clone(), RuleKind::BoolConst(true));
+        nested.constraints = vec![constraint(
+            bool_value.clone(),
+            RuleKind::Atom(Atom {
+                context_key: String::from("key"),
+                ..Default::default()
+            }),
+        )];
+        let malformed = feature(FeatureType::Bool, Some(bool_value), vec![nested]);
+        assert_eq!(
+            problem(&validator, &malformed),
+            Some(Problem::Invalid(EvalError::MalformedRule(
+                "empty comparison value"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_proto_types() {
+        let fds = FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                package: Some(String::from("example.v1")),
+                message_type: vec![DescriptorProto {
+                    name: Some(String::from("Config")),
+                    nested_type: vec![DescriptorProto {
+                        name: Some(String::from("Nested")),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        };
+        let validator = Validator::new(Some(&fds));
+        for type_name in [
+            "example.v1.Config",
+            "example.v1.Config.Nested",
+            "google.protobuf.Duration",
+        ] {
+            let f = feature(FeatureType::Proto, Some(any(type_name)), vec![]);
+            assert_eq!(problem(&validator, &f), None, "{type_name}");
+        }
+        let unknown = feature(FeatureType::Proto, Some(any("example.v1.Other")), vec![]);
+        assert_eq!(
+            problem(&validator, &unknown),
+            Some(Problem::UnknownType(String::from(
+                "type.googleapis.com/example.v1.Other"
+            )))
+        );
+        // without a file descriptor set, types can't be resolved
+        assert_eq!(problem(&Validator::new(None), &unknown), None);
+
+        let mixed = feature(
+            FeatureType::Proto,
+            Some(any("example.v1.Config")),
+            vec![constraint(
+                any("example.v1.Config.Nested"),
+                RuleKind::BoolConst(true),
+            )],
+        );
+        assert!(matches!(
+            problem(&validator, &mixed),
+            Some(Problem::TypeMismatch { .. })
+        ));
+    }
+}
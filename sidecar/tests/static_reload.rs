@@ -0,0 +1,354 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use prost::Message;
+use prost_types::{Any, DescriptorProto, FileDescriptorProto, FileDescriptorSet};
+use sidecar::{
+    gen::cli::lekko::{
+        backend::v1beta1::{self, GetRepositoryContentsResponse, Namespace},
+        feature::v1beta1::{Feature, FeatureType, Tree},
+    },
+    snapshot_cache::SnapshotCache,
+    source::{GitSource, SnapshotFileSource},
+    store::{NamespaceFilter, Store},
+    types::WatchMode,
+};
+
+// A config repo on disk, laid out the way git-sync checks it out: the contents are
+// in a directory named after the commit sha, behind a 'contents' symlink.
+struct TempRepo {
+    root: PathBuf,
+    contents: PathBuf,
+}
+
+impl TempRepo {
+    fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("sidecar-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join(".git")).unwrap();
+        let mut repo = Self {
+            contents: root.clone(),
+            root,
+        };
+        repo.checkout("abc123");
+        repo.swap();
+        repo
+    }
+
+    // Starts a new checkout for the commit sha, which is written to until it is
+    // swapped in.
+    fn checkout(&mut self, commit_sha: &str) {
+        self.contents = self.root.join(commit_sha);
+        fs::create_dir_all(self.contents.join("ns/gen/proto")).unwrap();
+        fs::create_dir_all(self.contents.join("proto")).unwrap();
+        fs::write(
+            self.contents.join("lekko.root.yaml"),
+            "namespaces:\n  - ns\n",
+        )
+        .unwrap();
+    }
+
+    // Points the contents link at the current checkout, atomically like git-sync.
+    fn swap(&self) {
+        let tmp = self.root.join("contents.tmp");
+        std::os::unix::fs::symlink(self.contents.file_name().unwrap(), &tmp).unwrap();
+        fs::rename(tmp, self.root.join("contents")).unwrap();
+    }
+
+    fn write_feature(&self, feature: Feature) {
+        let path = self
+            .contents
+            .join(format!("ns/gen/proto/{}.proto.bin", feature.key));
+        fs::write(path, feature.encode_to_vec()).unwrap();
+    }
+
+    fn write_image(&self, messages: &[&str]) {
+        fs::write(self.contents.join("proto/image.bin"), image(messages)).unwrap();
+    }
+}
+
+impl Drop for TempRepo {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+fn image(messages: &[&str]) -> Vec<u8> {
+    FileDescriptorSet {
+        file: vec![FileDescriptorProto {
+            name: Some(String::from("example/v1/example.proto")),
+            package: Some(String::from("example.v1")),
+            message_type: messages
+                .iter()
+                .map(|name| DescriptorProto {
+                    name: Some(name.to_string()),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }],
+    }
+    .encode_to_vec()
+}
+
+fn feature(key: &str, r#type: FeatureType, type_name: &str) -> Feature {
+    Feature {
+        key: key.to_owned(),
+        tree: Some(Tree {
+            default: Some(Any {
+                type_url: format!("type.googleapis.com/{type_name}"),
+                value: vec![],
+            }),
+            ..Default::default()
+        }),
+        r#type: r#type.into(),
+        ..Default::default()
+    }
+}
+
+async fn static_store(repo_path: &Path, watch_mode: WatchMode) -> Store {
+    let source = GitSource::new(repo_path.to_string_lossy().into_owned(), watch_mode).unwrap();
+    Store::from_source(Box::new(source), NamespaceFilter::default(), 1)
+        .await
+        .unwrap()
+}
+
+// The poll watcher compares modification times in whole seconds, so a change is
+// only noticed if it is made in a later second than the previous scan.
+async fn next_second() {
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+}
+
+// Waits for the store to pick up a change on disk.
+async fn wait_for(store: &Store, what: &str, done: impl Fn(&Store) -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(20);
+    while !done(store) {
+        assert!(Instant::now() < deadline, "timed out waiting for {what}");
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+fn message_names(store: &Store) -> Vec<String> {
+    let (_, _, fds) = store.get_repo_contents_local("", "").unwrap();
+    fds.unwrap()
+        .file
+        .iter()
+        .flat_map(|file| file.message_type.iter().map(|m| m.name().to_owned()))
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_reload_file_descriptor_set() {
+    // Files are changed in place, which native events ignore for git-sync layouts.
+    let repo = TempRepo::new("static-reload");
+    repo.write_image(&["Config"]);
+    repo.write_feature(feature(
+        "flag",
+        FeatureType::Bool,
+        "google.protobuf.BoolValue",
+    ));
+    let store = static_store(&repo.root, WatchMode::Poll).await;
+    assert_eq!(message_names(&store), ["Config"]);
+
+    // Adding a type only changes the proto image.
+    next_second().await;
+    repo.write_image(&["Config", "NewConfig"]);
+    wait_for(&store, "the new type", |store| {
+        message_names(store) == ["Config", "NewConfig"]
+    })
+    .await;
+
+    // A feature of the new type is validated against the reloaded image.
+    next_second().await;
+    repo.write_feature(feature(
+        "proto_flag",
+        FeatureType::Proto,
+        "example.v1.NewConfig",
+    ));
+    wait_for(&store, "the new feature", |store| {
+        let (_, namespaces, _) = store.get_repo_contents_local("ns", "proto_flag").unwrap();
+        !namespaces.is_empty()
+    })
+    .await;
+    assert_eq!(store.get_version_local(), "abc123");
+    assert_eq!(message_names(&store), ["Config", "NewConfig"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_git_sync_swap() {
+    let mut repo = TempRepo::new("git-sync-swap");
+    repo.write_image(&["Config"]);
+    repo.write_feature(feature(
+        "flag",
+        FeatureType::Bool,
+        "google.protobuf.BoolValue",
+    ));
+    let store = static_store(&repo.root, WatchMode::Native).await;
+    let mut changes = store.subscribe();
+
+    repo.checkout("def456");
+    repo.write_image(&["Config", "NewConfig"]);
+    for i in 0..10 {
+        repo.write_feature(feature(
+            &format!("proto_flag{i}"),
+            FeatureType::Proto,
+            "example.v1.NewConfig",
+        ));
+    }
+    // Nothing is loaded from the new checkout until it is swapped in.
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(store.get_version_local(), "abc123");
+
+    repo.swap();
+    wait_for(&store, "the new checkout", |store| {
+        store.get_version_local() == "def456"
+    })
+    .await;
+    let (_, namespaces, _) = store.get_repo_contents_local("ns", "").unwrap();
+    assert_eq!(namespaces[0].features.len(), 10);
+    assert_eq!(message_names(&store), ["Config", "NewConfig"]);
+    // The swap is loaded once.
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let event = changes.try_recv().unwrap();
+    assert_eq!(event.new_commit_sha, "def456");
+    assert!(changes.try_recv().is_err());
+}
+
+// Runs git in the directory, and returns what it prints.
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(["-c", "user.name=test", "-c", "user.email=test@lekko.dev"])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "git {args:?}: {output:?}");
+    String::from_utf8(output.stdout).unwrap().trim().to_owned()
+}
+
+fn commit_feature(work: &Path, key: &str) -> String {
+    fs::write(
+        work.join(format!("ns/gen/proto/{key}.proto.bin")),
+        feature(key, FeatureType::Bool, "google.protobuf.BoolValue").encode_to_vec(),
+    )
+    .unwrap();
+    git(work, &["add", "-A"]);
+    git(work, &["commit", "-q", "-m", key]);
+    git(work, &["rev-parse", "HEAD"])
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_git_ref() {
+    let dir = std::env::temp_dir().join(format!("sidecar-git-ref-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let work = dir.join("work");
+    fs::create_dir_all(work.join("ns/gen/proto")).unwrap();
+    fs::create_dir_all(work.join("proto")).unwrap();
+    fs::write(work.join("lekko.root.yaml"), "namespaces:\n  - ns\n").unwrap();
+    fs::write(work.join("proto/image.bin"), image(&["Config"])).unwrap();
+    git(&work, &["init", "-q", "-b", "main"]);
+    let first = commit_feature(&work, "flag");
+    git(&dir, &["clone", "-q", "--bare", "work", "bare.git"]);
+
+    let source = GitSource::at_rev(
+        dir.join("bare.git").to_string_lossy().into_owned(),
+        String::from("main"),
+        WatchMode::Native,
+    )
+    .unwrap();
+    let store = Store::from_source(Box::new(source), NamespaceFilter::default(), 1)
+        .await
+        .unwrap();
+    assert_eq!(store.get_version_local(), first);
+    let (_, namespaces, _) = store.get_repo_contents_local("ns", "flag").unwrap();
+    // The sha of a feature is the id of its blob.
+    assert_eq!(
+        namespaces[0].features[0].sha,
+        git(&work, &["rev-parse", "HEAD:ns/gen/proto/flag.proto.bin"])
+    );
+    assert_eq!(message_names(&store), ["Config"]);
+
+    // Changes that aren't committed aren't loaded from the working tree.
+    fs::write(work.join("proto/image.bin"), b"not an image").unwrap();
+    let source = GitSource::at_rev(
+        work.to_string_lossy().into_owned(),
+        String::from("HEAD"),
+        WatchMode::Native,
+    )
+    .unwrap();
+    let work_store = Store::from_source(Box::new(source), NamespaceFilter::default(), 1)
+        .await
+        .unwrap();
+    assert_eq!(work_store.get_version_local(), first);
+    assert_eq!(message_names(&work_store), ["Config"]);
+    git(&work, &["checkout", "-q", "proto/image.bin"]);
+
+    // Pushing to the bare repo moves the ref, and the new commit is loaded.
+    let second = commit_feature(&work, "other_flag");
+    git(&work, &["push", "-q", "../bare.git", "main"]);
+    wait_for(&store, "the pushed commit", |store| {
+        store.get_version_local() == second
+    })
+    .await;
+    let (_, namespaces, _) = store.get_repo_contents_local("ns", "").unwrap();
+    assert_eq!(namespaces[0].features.len(), 2);
+    let _ = fs::remove_dir_all(&dir);
+}
+
+fn snapshot(commit_sha: &str, features: &[&str]) -> GetRepositoryContentsResponse {
+    GetRepositoryContentsResponse {
+        commit_sha: commit_sha.to_owned(),
+        namespaces: vec![Namespace {
+            name: String::from("ns"),
+            features: features
+                .iter()
+                .map(|name| v1beta1::Feature {
+                    name: name.to_string(),
+                    sha: format!("{commit_sha}-{name}"),
+                    feature: Some(feature(
+                        name,
+                        FeatureType::Bool,
+                        "google.protobuf.BoolValue",
+                    )),
+                })
+                .collect(),
+        }],
+        file_descriptor_set: None,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_snapshot_file_reload() {
+    let dir = std::env::temp_dir().join(format!("sidecar-snapshot-reload-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let path = dir.join("example.snapshot");
+    let cache = SnapshotCache::at(&path);
+    cache.write(&snapshot("abc123", &["flag"])).unwrap();
+
+    let source = SnapshotFileSource::new(&path, WatchMode::Native).unwrap();
+    let store = Store::from_source(Box::new(source), NamespaceFilter::default(), 1)
+        .await
+        .unwrap();
+    assert_eq!(store.get_version_local(), "abc123");
+
+    // Snapshots are replaced atomically, the same way the snapshot cache writes them.
+    cache
+        .write(&snapshot("def456", &["flag", "other_flag"]))
+        .unwrap();
+    wait_for(&store, "the new snapshot", |store| {
+        store.get_version_local() == "def456"
+    })
+    .await;
+    let (_, namespaces, _) = store.get_repo_contents_local("ns", "").unwrap();
+    assert_eq!(namespaces[0].features.len(), 2);
+
+    // A corrupted snapshot is ignored, and the last one loaded is kept.
+    fs::write(&path, b"something else").unwrap();
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(store.get_version_local(), "def456");
+    let _ = fs::remove_dir_all(&dir);
+}